@@ -1,34 +1,25 @@
-use aoc_common::util::{self, Lexer, iter::ResultIteratorExtended, lexer};
+use aoc_common::util::{self, Lexer, iter::IteratorExtended, lexer};
 
-fn parse_food_line(s: &str) -> lexer::Result<Option<usize>> {
+fn parse_food_line(s: &str) -> lexer::Result<usize> {
     let mut lexer = Lexer::of(s);
-
-    let res = lexer.end().map(|()| None);
-    if res.is_ok() {
-        return res;
-    }
-
-    // If `res` is Err (i.e. `s` is not empty), try parsing a calorie number from the line
     let num = lexer.unsigned_number()?;
     lexer.end()?;
-    Ok(Some(num))
+    Ok(num)
 }
 
 pub fn get_n_highest<const N: usize>(
     lines: impl Iterator<Item = String>,
 ) -> util::GenericResult<(usize, usize)> {
     let mut elf_sums = Vec::new();
-    let mut food_lines = lines.map(|s| parse_food_line(&s)).end_on_error();
-
-    loop {
-        let sum = food_lines.by_ref().map_while(|num| num).sum();
-        // No more elves (or an error occured)
-        if sum == 0 {
-            break;
-        }
-        elf_sums.push(sum);
+    let mut sections = lines.sections();
+
+    while let Some(food_lines) = sections.section() {
+        elf_sums.push(
+            food_lines
+                .map(|s| parse_food_line(&s))
+                .sum::<lexer::Result<_>>()?,
+        );
     }
-    food_lines.into_err()?;
 
     let len = elf_sums.len();
     assert!(N < len, "N is too big");
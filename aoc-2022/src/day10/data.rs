@@ -1,3 +1,5 @@
+use aoc_common::util::{MatrixIndex, VecMatrix};
+
 pub struct Cpu {
     cycle: usize,
 }
@@ -44,38 +46,26 @@ const PX_DIMMED: char = ' ';
 const PX_LIT: char = '#';
 
 pub struct Screen {
-    render: String,
-    width: usize,
+    pixels: VecMatrix<bool>,
 }
 
 impl Screen {
     pub fn new(width: usize, height: usize) -> Self {
-        // I am using already rendered screen as internal representation to save allocations:
-        // `width` bytes + newline, repeat `height` times
-        let render = (0..height)
-            .flat_map(|_| std::iter::repeat_n(PX_DIMMED, width).chain(std::iter::once('\n')))
-            .collect();
-
-        Self { render, width }
+        Self {
+            pixels: VecMatrix::with_data(vec![false; width * height], width),
+        }
     }
 
     pub fn light_px(&mut self, x: usize, y: usize) {
-        *self.get_px(x, y) = PX_LIT as u8;
+        *self
+            .pixels
+            .get_mut(MatrixIndex { row: y, col: x })
+            .expect("Cycles overflowed!") = true;
     }
 
     pub fn into_render(self) -> String {
-        self.render
-    }
-
-    fn get_px(&mut self, x: usize, y: usize) -> &mut u8 {
-        // `y * (width + 1)` to skip `y` screen lines + `y` newline chars
-        let idx = y * (self.width + 1) + x;
-
-        // SAFETY: Safe to write into underlying array because self.render
-        // consists of either PX_DIMMED, PX_LIT or '\n', which are all ASCII
-        unsafe { self.render.as_bytes_mut() }
-            .get_mut(idx)
-            .expect("Cycles overflowed!")
+        self.pixels
+            .render(|&lit| if lit { PX_LIT } else { PX_DIMMED })
     }
 }
 
@@ -15,16 +15,13 @@ enum Command {
 fn parse_command(s: &str) -> lexer::Result<Command> {
     let mut lexer = Lexer::of(s);
 
-    if lexer.literal("noop").is_ok() {
-        lexer.end()?;
-        return Ok(Command::Noop);
-    }
-
-    lexer.literal("addx ")?;
-    let num = lexer.number()?;
+    let command = match lexer.one_of(["noop", "addx "])? {
+        "noop" => Command::Noop,
+        _ => Command::Add(lexer.number()?),
+    };
     lexer.end()?;
 
-    Ok(Command::Add(num))
+    Ok(command)
 }
 
 pub fn get_signal_strengths(
@@ -1,14 +1,13 @@
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
+use aoc_common::util::{self, Direction8, Point, SparseGrid};
 
-type Coord = MatrixIndex;
+type Coord = Point;
 
-const SAND_SOURCE: Coord = Coord { row: 0, col: 500 };
-const FLOOR_OFFSET: usize = 2;
+const SAND_SOURCE: Coord = Coord::new(0, 500);
+const FLOOR_OFFSET: i64 = 2;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Stone,
-    Air,
     Sand,
 }
 
@@ -32,102 +31,72 @@ impl StoneLine {
     }
 
     fn iter_tiles(&self) -> impl Iterator<Item = Coord> {
-        let &Self {
-            from: Coord { row: from_row, col: from_col },
-            to: Coord { row: to_row, col: to_col },
-        } = self;
-
-        if from_row == to_row {
-            // Horizontal line
-            let (from_col, to_col) = (usize::min(from_col, to_col), usize::max(from_col, to_col));
-            (from_col..=to_col)
-                .map(Box::new(move |col| Coord { row: to_row, col }) as Box<dyn Fn(usize) -> Coord>)
-        } else {
-            // Vertical line
-            let (from_row, to_row) = (usize::min(from_row, to_row), usize::max(from_row, to_row));
-            (from_row..=to_row)
-                .map(Box::new(move |row| Coord { row, col: to_col }) as Box<dyn Fn(usize) -> Coord>)
-        }
+        let diff = self.to - self.from;
+        let step = Coord::new(diff.row.signum(), diff.col.signum());
+        let length = diff.row.abs().max(diff.col.abs());
+
+        (0..=length).scan(self.from, move |tile, _| {
+            let current = *tile;
+            *tile = current + step;
+            Some(current)
+        })
     }
 }
 
+// Air is wherever there is no tile, so the cave is not limited in width
 struct Cave {
-    tilemap: VecMatrix<Tile>,
+    tiles: SparseGrid<Tile>,
+    floor: i64,
 }
 
 impl Cave {
     fn new(lines: &[StoneLine]) -> Self {
-        let max_row = lines
-            .iter()
-            .flat_map(|line| [line.from.row, line.to.row])
-            .max()
-            .unwrap();
-
-        let width = 1000;
-        let height = max_row + FLOOR_OFFSET;
-
-        let mut tilemap = VecMatrix::with_data(vec![Tile::Air; width * height], width);
+        let mut tiles = SparseGrid::new();
         for line in lines {
-            for tile_coord in line.iter_tiles() {
-                tilemap[tile_coord] = Tile::Stone;
-            }
+            tiles.extend(line.iter_tiles().map(|tile| (tile, Tile::Stone)));
         }
 
-        Self { tilemap }
+        let max_row = tiles.bounds().map_or(0, |(_, max)| max.row);
+        Self { tiles, floor: max_row + FLOOR_OFFSET }
     }
 
     fn place_sand_unit(&mut self, sand_source: Coord) -> Option<Coord> {
-        if self.tilemap[sand_source] != Tile::Air {
+        if self.tiles.contains(sand_source) {
             return None;
         }
 
         let mut sand_unit = sand_source;
         loop {
             // Have we reached bottom of the cave?
-            if sand_unit.row == self.tilemap.height() - 1 {
+            if sand_unit.row == self.floor - 1 {
                 // There is floor at the bottom -- place it in
-                self.tilemap[sand_unit] = Tile::Sand;
+                self.tiles.insert(sand_unit, Tile::Sand);
                 break Some(sand_unit);
             }
 
-            // Can we fall down below?
-            let down_below = Coord {
-                row: sand_unit.row + 1,
-                col: sand_unit.col,
-            };
-            if self.tilemap[down_below] == Tile::Air {
-                sand_unit = down_below;
-                continue;
-            }
-
-            // Can we fall down-left?
-            let down_left = Coord {
-                row: sand_unit.row + 1,
-                col: sand_unit.col - 1,
-            };
-            if self.tilemap[down_left] == Tile::Air {
-                sand_unit = down_left;
-                continue;
-            }
-
-            // Can we fall down-right?
-            let down_right = Coord {
-                row: sand_unit.row + 1,
-                col: sand_unit.col + 1,
-            };
-            if self.tilemap[down_right] == Tile::Air {
-                sand_unit = down_right;
+            // Can we fall down below, down-left or down-right?
+            let next = [
+                Direction8::Down,
+                Direction8::DownLeft,
+                Direction8::DownRight,
+            ]
+            .into_iter()
+            .map(|direction| sand_unit.step(direction, 1))
+            .find(|&next| !self.tiles.contains(next));
+
+            if let Some(next) = next {
+                sand_unit = next;
                 continue;
             }
 
             // If we can't fall anywhere, looks like we reached a stable point
-            self.tilemap[sand_unit] = Tile::Sand;
+            self.tiles.insert(sand_unit, Tile::Sand);
             break Some(sand_unit);
         }
     }
 
-    const fn get_floor_level(&self) -> usize {
-        self.tilemap.height()
+    const fn get_floor_level(&self) -> i64 {
+        self.floor
     }
 }
 
@@ -122,7 +122,7 @@ fn draw_sensor_map<const N: usize>(sensors: &[Sensor]) {
 
     for tile_row in tilemap {
         for tile_cell in tile_row {
-            print!(
+            eprint!(
                 "{}",
                 match tile_cell {
                     Tile::Sensor => 'S',
@@ -132,7 +132,7 @@ fn draw_sensor_map<const N: usize>(sensors: &[Sensor]) {
                 }
             );
         }
-        println!();
+        eprintln!();
     }
 }
 
@@ -56,13 +56,13 @@ fn draw_bitrow<const LEN: usize>(mut row: u16) {
     row = row.reverse_bits();
     for _ in 0..LEN {
         if (1u16 << (u16::BITS - 1)) & row == 0 {
-            print!(".");
+            eprint!(".");
         } else {
-            print!("#");
+            eprint!("#");
         }
         row <<= 1;
     }
-    println!();
+    eprintln!();
 }
 
 #[derive(Clone)]
@@ -180,7 +180,7 @@ impl Chamber {
         for &(mut row_data) in &self.tilemap {
             draw_bitrow::<9>(row_data);
         }
-        println!();
+        eprintln!();
     }
 }
 
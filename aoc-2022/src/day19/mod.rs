@@ -189,13 +189,11 @@ fn parse_blueprint(data: &str) -> util::GenericResult<Blueprint> {
 
 fn find_max_geodes(blueprint: &Blueprint, minutes: usize) -> u16 {
     let mut states = HashSet::from([ResourceState::new()]);
-    for i in 1..minutes {
+    for _ in 1..minutes {
         states = states
             .iter()
             .flat_map(|s| blueprint.generate_states(s))
             .collect();
-
-        println!("After {} minutes: len = {}", i, states.len());
     }
 
     states
@@ -213,12 +211,10 @@ pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
         let blueprint = parse_blueprint(&line)?;
 
         let regular = find_max_geodes(&blueprint, 24) as usize;
-        println!("Blueprint {}: {}", num + 1, regular);
         quality += (num + 1) * regular;
 
         if num < 3 {
             let thirty_two = find_max_geodes(&blueprint, 32) as usize;
-            println!("Blueprint {}: {}", num + 1, thirty_two);
             first_three *= thirty_two;
         }
     }
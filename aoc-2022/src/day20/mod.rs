@@ -2,8 +2,9 @@ use aoc_common::util;
 
 const DECRYPTION_KEY: i64 = 811_589_153;
 
-pub fn decrypt_table(lines: impl Iterator<Item = String>) -> util::GenericResult<(i64, i64)> {
-    let data = lines
+pub fn decrypt_table(input: &str) -> util::GenericResult<(i64, i64)> {
+    let data = input
+        .lines()
         .map(|line| line.parse::<i64>().map(|num| num * DECRYPTION_KEY))
         .collect::<Result<Vec<_>, _>>()?;
 
@@ -69,3 +70,15 @@ pub fn decrypt_table(lines: impl Iterator<Item = String>) -> util::GenericResult
 
     Ok((0, sum))
 }
+
+aoc_common::examples! {
+    text: decrypt_table;
+    example: "
+1
+2
+-3
+3
+-2
+0
+4" => _, 1_623_178_306;
+}
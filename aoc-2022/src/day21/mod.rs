@@ -1,9 +1,12 @@
 use std::{cell::RefCell, collections::HashMap};
 
-use aoc_common::util;
+use aoc_common::util::{self, Interner};
 
 type Id = usize;
 
+const ROOT: Id = 0;
+const HUMN: Id = 1;
+
 #[derive(Debug)]
 enum Operation {
     Add,
@@ -86,7 +89,7 @@ impl Job {
 }
 
 struct MonkeySet {
-    name_to_id: HashMap<String, Id>,
+    names: Interner,
     jobs: Vec<Option<Job>>,
     operation_cache: RefCell<HashMap<Id, i64>>,
     depends_cache: RefCell<HashMap<Id, bool>>,
@@ -95,7 +98,7 @@ struct MonkeySet {
 impl MonkeySet {
     fn new() -> Self {
         Self {
-            name_to_id: HashMap::new(),
+            names: Interner::with_names(["root", "humn"]),
             jobs: Vec::new(),
             operation_cache: RefCell::new(HashMap::new()),
             depends_cache: RefCell::new(HashMap::new()),
@@ -103,14 +106,8 @@ impl MonkeySet {
     }
 
     fn get_or_create_monkey(&mut self, name: &str) -> Id {
-        if let Some(&id) = self.name_to_id.get(name) {
-            return id;
-        }
-
-        let id = self.jobs.len();
-        self.name_to_id.insert(name.to_owned(), id);
-        self.jobs.push(None);
-
+        let id = self.names.intern(name);
+        self.jobs.resize_with(self.names.len(), || None);
         id
     }
 
@@ -124,16 +121,10 @@ impl MonkeySet {
     }
 
     fn get_job_by_id(&self, id: Id) -> &Job {
-        self.jobs[id]
-            .as_ref()
-            .expect("All monkeys should have a job defined!")
-    }
-
-    fn get_monkey_id(&self, name: &str) -> Id {
-        *self
-            .name_to_id
-            .get(name)
-            .unwrap_or_else(|| panic!("{name} not found"))
+        self.jobs
+            .get(id)
+            .and_then(Option::as_ref)
+            .unwrap_or_else(|| panic!("{} doesn't have a job", &self.names[id]))
     }
 
     fn get_result_by_id(&self, id: Id) -> i64 {
@@ -191,14 +182,7 @@ impl MonkeySet {
         }
     }
 
-    fn get_result(&self, name: &str) -> i64 {
-        self.get_result_by_id(self.get_monkey_id(name))
-    }
-
-    fn get_operand(&self, root_name: &str, operand_name: &str) -> i64 {
-        let root_id = self.get_monkey_id(root_name);
-        let operand_id = self.get_monkey_id(operand_name);
-
+    fn get_operand(&self, root_id: Id, operand_id: Id) -> i64 {
         let root_job = self.get_job_by_id(root_id);
 
         if let Job::Operation { id1, id2, op: _ } = root_job {
@@ -208,7 +192,7 @@ impl MonkeySet {
                 self.get_operand_by_id(*id2, operand_id, self.get_result_by_id(*id1))
             }
         } else {
-            0 // root_name doesn't depend on operand_name
+            0 // root_id doesn't depend on operand_id
         }
     }
 }
@@ -248,8 +232,8 @@ pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(i
         monkey_set.add_monkey_job(name, job);
     }
 
-    let root_num = monkey_set.get_result("root");
-    let humn_num = monkey_set.get_operand("root", "humn");
+    let root_num = monkey_set.get_result_by_id(ROOT);
+    let humn_num = monkey_set.get_operand(ROOT, HUMN);
 
     Ok((root_num, humn_num))
 }
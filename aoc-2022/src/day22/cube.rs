@@ -4,7 +4,7 @@ use aoc_common::util::iter::ArrayIterators;
 
 use super::data::{BoundsMapping, Coord, Direction, TileMap};
 
-const EDGE_LENGTH: usize = 50;
+pub const EDGE_LENGTH: usize = 50;
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
 struct Vertex {
@@ -4,7 +4,7 @@ mod data;
 use aoc_common::util::{self, VecMatrix};
 
 use self::{
-    cube::get_cube_mapping,
+    cube::{EDGE_LENGTH, get_cube_mapping},
     data::{BoundsMapping, Coord, Direction, Tile, TileMap},
 };
 
@@ -136,9 +136,11 @@ pub fn traverse_map(
         if line.is_empty() {
             break;
         }
-        let len = line.len();
-
-        assert!(tilemap.width() >= len + 2);
+        aoc_common::assume!(
+            line.len() + 2 <= tilemap.width(),
+            "rows of the map are at most {} tiles long",
+            tilemap.width() - 2
+        );
 
         tilemap.push(None);
         tilemap.extend(line.chars().map(tile_from_char));
@@ -150,6 +152,12 @@ pub fn traverse_map(
 
     let move_data = lines.next().expect("Move data should exist");
 
+    let tile_count = tilemap.iter().flatten().count();
+    aoc_common::assume!(
+        tile_count == 6 * EDGE_LENGTH * EDGE_LENGTH,
+        "the cube faces are {EDGE_LENGTH} by {EDGE_LENGTH} tiles"
+    );
+
     let mappings = [flat_mapping(&tilemap), get_cube_mapping(&tilemap)];
     let mut passwords = [0, 0];
 
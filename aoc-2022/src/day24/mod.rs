@@ -122,7 +122,7 @@ fn print_states(states: &HashSet<Coord>) {
         .map(|c| format!("[{},{}]", c.row, c.col))
         .collect::<Vec<_>>();
     formatted.sort();
-    println!("[{}]", formatted.join(", "));
+    eprintln!("[{}]", formatted.join(", "));
 }
 
 pub fn count_path_minutes(
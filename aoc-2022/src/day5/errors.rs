@@ -12,11 +12,41 @@ pub enum CrateInputError {
 
 impl std::fmt::Display for CrateInputError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::ParsingFailed(err) => write!(f, "{err}"),
+            Self::EmptySpaceUnderCrate { line, stack_num } => {
+                write!(
+                    f,
+                    "stack {stack_num} has an empty space under a crate in `{line}`"
+                )
+            }
+            Self::NoEmptyLineAfterSeparator => {
+                write!(f, "expected an empty line after the stack numbers")
+            }
+            Self::IdenticalStackNumbers { line } => {
+                write!(
+                    f,
+                    "crates are moved to the stack they come from in `{line}`"
+                )
+            }
+            Self::StackNumbersTooBig { line } => {
+                write!(f, "there is no such stack in `{line}`")
+            }
+            Self::NotEnoughCrates { line } => {
+                write!(f, "the stack has fewer crates than are moved in `{line}`")
+            }
+        }
     }
 }
 
-impl std::error::Error for CrateInputError {}
+impl std::error::Error for CrateInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ParsingFailed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl From<lexer::Error> for CrateInputError {
     fn from(err: lexer::Error) -> Self {
@@ -1,70 +1,36 @@
-use std::{
-    collections::HashSet,
-    ops::{Add, Sub},
-};
-
-use aoc_common::util::{self, Lexer};
-
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-struct Coords(i64, i64);
-
-impl Sub<Self> for Coords {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0, self.1 - rhs.1)
-    }
-}
-
-impl Add<Self> for Coords {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0, self.1 + rhs.1)
-    }
-}
+use aoc_common::util::{self, Direction4, Lexer, Point, SparseGrid};
 
 struct Rope<const N: usize> {
-    knots: [Coords; N],
+    knots: [Point; N],
 }
 
 impl<const N: usize> Rope<N> {
     const fn new() -> Self {
-        Self { knots: [Coords(0, 0); N] }
+        Self { knots: [Point::new(0, 0); N] }
     }
 
     fn follow(&mut self) {
         for ind in 1..N {
             let diff = self.knots[ind - 1] - self.knots[ind];
-            let diff_abs = Coords(diff.0.abs(), diff.1.abs());
-
-            let change_abs = if diff_abs.0 < 2 && diff_abs.1 < 2 {
-                Coords(0, 0)
-            } else if diff_abs.0 == 2 {
-                if diff_abs.1 > 0 { Coords(1, 1) } else { Coords(1, 0) }
-            } else if diff_abs.0 == 1 {
-                Coords(1, 1)
-            } else {
-                Coords(0, 1)
-            };
 
-            let change = Coords(
-                change_abs.0 * diff.0.signum(),
-                change_abs.1 * diff.1.signum(),
-            );
+            // Knots which are still touching stay, others move one step towards the previous knot
+            if diff.row.abs() < 2 && diff.col.abs() < 2 {
+                continue;
+            }
 
-            self.knots[ind] = self.knots[ind] + change;
+            self.knots[ind] = self.knots[ind] + Point::new(diff.row.signum(), diff.col.signum());
         }
     }
 
     fn perform(&mut self, op: char) {
-        match op {
-            'U' => self.knots[0].1 += 1,
-            'D' => self.knots[0].1 -= 1,
-            'L' => self.knots[0].0 -= 1,
-            'R' => self.knots[0].0 += 1,
+        let direction = match op {
+            'U' => Direction4::Up,
+            'D' => Direction4::Down,
+            'L' => Direction4::Left,
+            'R' => Direction4::Right,
             _ => panic!("Invalid operation code"),
-        }
+        };
+        self.knots[0] = self.knots[0].step(direction, 1);
 
         self.follow();
     }
@@ -75,10 +41,10 @@ pub fn count_unique_positions(
 ) -> util::GenericResult<(usize, usize)> {
     let mut rope = Rope::<10>::new();
 
-    let mut set_short = HashSet::new();
-    let mut set_long = HashSet::new();
-    set_short.insert(Coords(0, 0));
-    set_long.insert(Coords(0, 0));
+    let mut set_short = SparseGrid::new();
+    let mut set_long = SparseGrid::new();
+    set_short.insert(Point::new(0, 0), ());
+    set_long.insert(Point::new(0, 0), ());
 
     for line in lines {
         let mut lexer = Lexer::of(&line);
@@ -90,8 +56,8 @@ pub fn count_unique_positions(
 
         for _ in 0..count {
             rope.perform(op);
-            set_short.insert(rope.knots[1]);
-            set_long.insert(rope.knots[9]);
+            set_short.insert(rope.knots[1], ());
+            set_long.insert(rope.knots[9], ());
         }
     }
 
@@ -35,3 +35,30 @@ pub mod day6;
 pub mod day7;
 pub mod day8;
 pub mod day9;
+
+aoc_common::register_year! {
+    year: 2022,
+    1 => day1::get_n_highest::<3>, "Day 1: Calorie Counting";
+    2 => day2::get_total_scores, "Day 2: Rock Paper Scissors";
+    3 => day3::get_misplacings::<3>, "Day 3: Rucksack Reorganization";
+    4 => day4::count_overlaps, "Day 4: Camp Cleanup";
+    5 => day5::reorder_stacks::<9>, "Day 5: Supply Stacks";
+    6 => day6::find_markers::<4, 14>, "Day 6: Tuning Trouble";
+    7 => day7::get_directory_sizes, "Day 7: No Space Left On Device";
+    8 => day8::find_visible_trees, "Day 8: Treetop Tree House";
+    9 => day9::count_unique_positions, "Day 9: Rope Bridge";
+    10 => day10::get_signal_strengths, "Day 10: Cathode-Ray Tube";
+    12 => day12::find_shortest_distance, "Day 12: Hill Climbing Algorithm";
+    13 => day13::determine_order, "Day 13: Distress Signal";
+    14 => day14::count_stable_units, "Day 14: Regolith Reservoir";
+    15 => day15::find_missing_beacon, "Day 15: Beacon Exclusion Zone";
+    17 => day17::tetris_simulator, "Day 17: Pyroclastic Flow";
+    18 => day18::find_surface_area, "Day 18: Boiling Boulders";
+    19 => day19::get_answer, "Day 19: DISPLAY NAME";
+    20 => text(day20::decrypt_table), "Day 20: Grove Positioning System";
+    21 => day21::get_answer, "Day 21: DISPLAY NAME";
+    22 => day22::traverse_map, "Day 22: Monkey Map";
+    23 => day23::spread_elves, "Day 23: Unstable Diffusion";
+    24 => day24::count_path_minutes, "Day 24: Blizzard Basin";
+    25 => day25::translate_numbers, "Day 25: Full of Hot Air";
+}
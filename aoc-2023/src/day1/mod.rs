@@ -60,3 +60,13 @@ pub fn calibrate(lines: impl Iterator<Item = String>) -> util::GenericResult<(us
     }
     Ok((calibration, calibration_with_text))
 }
+
+aoc_common::examples! {
+    solution: calibrate;
+    part1: "
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+" => 142, _;
+}
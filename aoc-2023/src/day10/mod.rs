@@ -1,25 +1,6 @@
 use std::collections::HashSet;
 
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
-
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-
-impl Direction {
-    const fn opposite(self) -> Self {
-        match self {
-            Self::North => Self::South,
-            Self::South => Self::North,
-            Self::West => Self::East,
-            Self::East => Self::West,
-        }
-    }
-}
+use aoc_common::util::{self, Direction4 as Direction, VecMatrix};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -30,15 +11,15 @@ enum Tile {
 
 impl From<char> for Tile {
     fn from(value: char) -> Self {
-        use Direction::{East, North, South, West};
+        use Direction::{Down, Left, Right, Up};
         use Tile::{Ground, Pipe, Start};
         match value {
-            '|' => Pipe(North, South),
-            '-' => Pipe(West, East),
-            'L' => Pipe(North, East),
-            'J' => Pipe(North, West),
-            '7' => Pipe(South, West),
-            'F' => Pipe(South, East),
+            '|' => Pipe(Up, Down),
+            '-' => Pipe(Left, Right),
+            'L' => Pipe(Up, Right),
+            'J' => Pipe(Up, Left),
+            '7' => Pipe(Down, Left),
+            'F' => Pipe(Down, Right),
             '.' => Ground,
             'S' => Start,
             _ => panic!("Unknown tile: {value}"),
@@ -50,14 +31,14 @@ impl Tile {
     const fn has_south_pipe(self) -> bool {
         matches!(
             self,
-            Self::Pipe(Direction::South, _) | Self::Pipe(_, Direction::South)
+            Self::Pipe(Direction::Down, _) | Self::Pipe(_, Direction::Down)
         )
     }
 
     const fn has_east_pipe(self) -> bool {
         matches!(
             self,
-            Self::Pipe(Direction::East, _) | Self::Pipe(_, Direction::East)
+            Self::Pipe(Direction::Right, _) | Self::Pipe(_, Direction::Right)
         )
     }
 
@@ -72,20 +53,6 @@ impl Tile {
     }
 }
 
-const fn next_idx(
-    tilemap: &VecMatrix<Tile>,
-    idx: MatrixIndex,
-    direction: Direction,
-) -> Option<MatrixIndex> {
-    use Direction::{East, North, South, West};
-    match direction {
-        North => tilemap.next_up(idx),
-        South => tilemap.next_down(idx),
-        West => tilemap.next_left(idx),
-        East => tilemap.next_right(idx),
-    }
-}
-
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Closure {
     Outside,
@@ -120,13 +87,13 @@ pub fn find_enclosing_loop(
         .expect("Starting tile should exist");
 
     let mut valid_pipes = [
-        Direction::North,
-        Direction::South,
-        Direction::West,
-        Direction::East,
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
     ]
     .into_iter()
-    .filter_map(|d| next_idx(&tilemap, start_idx, d).map(|idx| (d, idx)))
+    .filter_map(|d| tilemap.step(start_idx, d, 1).map(|idx| (d, idx)))
     .filter_map(|(d, idx)| tilemap[idx].next_direction(d).map(|_| d));
 
     let pipe1 = valid_pipes
@@ -143,8 +110,9 @@ pub fn find_enclosing_loop(
             let direction = tilemap[*idx]
                 .next_direction(*direction)
                 .expect("Main loop cannot be broken");
-            let idx =
-                next_idx(&tilemap, *idx, direction).expect("Main loop cannot go out of bounds");
+            let idx = tilemap
+                .step(*idx, direction, 1)
+                .expect("Main loop cannot go out of bounds");
             if idx == start_idx { None } else { Some((idx, direction)) }
         })
         .map(|(idx, _)| idx)
@@ -183,3 +151,37 @@ pub fn find_enclosing_loop(
 
     Ok((loop_tiles.len() / 2, enclosed_ground))
 }
+
+aoc_common::examples! {
+    solution: find_enclosing_loop;
+    part1: "
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+" => 8, _;
+    part2: "
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+" => _, 4;
+    larger: "
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+" => _, 10;
+}
@@ -43,3 +43,19 @@ pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
 
     Ok((distance_sum / 2, 0))
 }
+
+aoc_common::examples! {
+    solution: get_answer;
+    example: "
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+" => 82_000_210, _;
+}
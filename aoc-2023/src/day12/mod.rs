@@ -140,3 +140,15 @@ pub fn count_possible_states(
 
     Ok((0, count_sum))
 }
+
+aoc_common::examples! {
+    solution: count_possible_states;
+    example: "
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+" => _, 525_152;
+}
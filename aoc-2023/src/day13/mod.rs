@@ -1,4 +1,4 @@
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
+use aoc_common::util::{self, MatrixView, Orientation, VecMatrix, iter::IteratorExtended};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -16,102 +16,48 @@ impl From<char> for Tile {
     }
 }
 
-fn has_vertical_mirror_at(tilemap: &VecMatrix<Tile>, at_col: usize, smudge_total: usize) -> bool {
+// Mirror between `at_row` and the row below it, columns are checked on a transposed view
+fn has_mirror_at(tilemap: &MatrixView<Tile>, at_row: usize, smudge_total: usize) -> bool {
     let mut smudge_count = 0;
 
-    for row in 0..tilemap.height() {
-        let mut left = MatrixIndex { row, col: at_col };
-        let mut right = MatrixIndex { row, col: at_col + 1 };
-        loop {
-            if tilemap[left] != tilemap[right] {
-                smudge_count += 1;
-                if smudge_count > smudge_total {
-                    return false;
-                }
-            }
+    for (up, down) in (0..=at_row).rev().zip((at_row + 1)..tilemap.height()) {
+        smudge_count += tilemap
+            .row(up)
+            .zip(tilemap.row(down))
+            .filter(|(up, down)| up != down)
+            .count();
 
-            if let (Some(next_left), Some(next_right)) =
-                (tilemap.next_left(left), tilemap.next_right(right))
-            {
-                left = next_left;
-                right = next_right;
-            } else {
-                break;
-            }
+        if smudge_count > smudge_total {
+            return false;
         }
     }
 
     smudge_count == smudge_total
 }
 
-fn has_horizontal_mirror_at(tilemap: &VecMatrix<Tile>, at_row: usize, smudge_total: usize) -> bool {
-    let mut smudge_count = 0;
-
-    for col in 0..tilemap.width() {
-        let mut up = MatrixIndex { col, row: at_row };
-        let mut down = MatrixIndex { col, row: at_row + 1 };
-        loop {
-            if tilemap[up] != tilemap[down] {
-                smudge_count += 1;
-                if smudge_count > smudge_total {
-                    return false;
-                }
-            }
-
-            if let (Some(next_up), Some(next_down)) = (tilemap.next_up(up), tilemap.next_down(down))
-            {
-                up = next_up;
-                down = next_down;
-            } else {
-                break;
-            }
-        }
-    }
-
-    smudge_count == smudge_total
+fn sum_mirror_rows(tilemap: &MatrixView<Tile>, smudge_total: usize) -> usize {
+    (0..tilemap.height().saturating_sub(1))
+        .filter(|&row| has_mirror_at(tilemap, row, smudge_total))
+        .map(|row| row + 1)
+        .sum()
 }
 
 fn find_mirrors(tilemap: &VecMatrix<Tile>, smudge_total: usize) -> usize {
-    let mut mirror_sum = 0;
-
-    for col in 0..(tilemap.width() - 1) {
-        if has_vertical_mirror_at(tilemap, col, smudge_total) {
-            mirror_sum += col + 1;
-        }
-    }
-
-    for row in 0..(tilemap.height() - 1) {
-        if has_horizontal_mirror_at(tilemap, row, smudge_total) {
-            mirror_sum += 100 * (row + 1);
-        }
-    }
-
-    mirror_sum
+    sum_mirror_rows(&tilemap.transposed(), smudge_total)
+        + 100 * sum_mirror_rows(&tilemap.view(Orientation::IDENTITY), smudge_total)
 }
 
 pub fn count_mirrors(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
     let mut mirror_sum = 0;
     let mut mirror_sum_with_smudge = 0;
 
-    let mut lines = lines.peekable();
+    let mut sections = lines.sections();
 
-    loop {
-        let pattern_lines = lines
-            .by_ref()
-            .take_while(|line| !line.is_empty())
-            .collect::<Vec<_>>();
-
-        let mut tilemap = VecMatrix::new(pattern_lines[0].len());
-        for line in pattern_lines {
-            tilemap.extend(line.chars().map(Tile::from));
-        }
+    while let Some(pattern_lines) = sections.section() {
+        let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(pattern_lines)?;
 
         mirror_sum += find_mirrors(&tilemap, 0);
         mirror_sum_with_smudge += find_mirrors(&tilemap, 1);
-
-        if lines.peek().is_none() {
-            break;
-        }
     }
 
     Ok((mirror_sum, mirror_sum_with_smudge))
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
+use aoc_common::util::{self, MatrixIndex, MatrixViewMut, Orientation, VecMatrix};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -20,7 +20,9 @@ impl From<char> for Tile {
     }
 }
 
-fn tilt_north(tilemap: &mut VecMatrix<Tile>) {
+// Rolls circles to the top of the view, other directions are tilted by looking at the map from
+// another side
+fn tilt(tilemap: &mut MatrixViewMut<Tile>) {
     for col in 0..tilemap.width() {
         let mut first_row = 0;
         let mut circle_count = 0;
@@ -50,109 +52,14 @@ fn tilt_north(tilemap: &mut VecMatrix<Tile>) {
     }
 }
 
-fn tilt_south(tilemap: &mut VecMatrix<Tile>) {
-    for col in 0..tilemap.width() {
-        let mut last_row = tilemap.height() - 1;
-        let mut circle_count = 0;
-
-        for row in (0..tilemap.height()).rev() {
-            let idx = MatrixIndex { col, row };
-            match tilemap[idx] {
-                Tile::Empty => {}
-                Tile::Square => {
-                    for row in (last_row - circle_count + 1)..=last_row {
-                        tilemap[MatrixIndex { col, row }] = Tile::Circle;
-                    }
-
-                    last_row = row.saturating_sub(1);
-                    circle_count = 0;
-                }
-                Tile::Circle => {
-                    circle_count += 1;
-                    tilemap[idx] = Tile::Empty;
-                }
-            }
-        }
-
-        for row in (last_row - circle_count + 1)..=last_row {
-            tilemap[MatrixIndex { col, row }] = Tile::Circle;
-        }
-    }
-}
-
-fn tilt_west(tilemap: &mut VecMatrix<Tile>) {
-    for row in 0..tilemap.height() {
-        let mut first_col = 0;
-        let mut circle_count = 0;
-
-        for col in 0..tilemap.width() {
-            let idx = MatrixIndex { col, row };
-            match tilemap[idx] {
-                Tile::Empty => {}
-                Tile::Square => {
-                    for col in first_col..(first_col + circle_count) {
-                        tilemap[MatrixIndex { col, row }] = Tile::Circle;
-                    }
-
-                    first_col = col + 1;
-                    circle_count = 0;
-                }
-                Tile::Circle => {
-                    circle_count += 1;
-                    tilemap[idx] = Tile::Empty;
-                }
-            }
-        }
-
-        for col in first_col..(first_col + circle_count) {
-            tilemap[MatrixIndex { col, row }] = Tile::Circle;
-        }
-    }
-}
-
-fn tilt_east(tilemap: &mut VecMatrix<Tile>) {
-    for row in 0..tilemap.height() {
-        let mut last_col = tilemap.width() - 1;
-        let mut circle_count = 0;
-
-        for col in (0..tilemap.width()).rev() {
-            let idx = MatrixIndex { col, row };
-            match tilemap[idx] {
-                Tile::Empty => {}
-                Tile::Square => {
-                    for col in (last_col - circle_count + 1)..=last_col {
-                        tilemap[MatrixIndex { col, row }] = Tile::Circle;
-                    }
-
-                    last_col = col.saturating_sub(1);
-                    circle_count = 0;
-                }
-                Tile::Circle => {
-                    circle_count += 1;
-                    tilemap[idx] = Tile::Empty;
-                }
-            }
-        }
-
-        for col in (last_col - circle_count + 1)..=last_col {
-            tilemap[MatrixIndex { col, row }] = Tile::Circle;
-        }
-    }
-}
-
 #[allow(dead_code)]
 fn draw(tilemap: &VecMatrix<Tile>) {
-    for (idx, tile) in tilemap.iter_enumerate() {
-        match tile {
-            Tile::Empty => print!("."),
-            Tile::Square => print!("#"),
-            Tile::Circle => print!("O"),
-        }
-        if idx.col == tilemap.width() - 1 {
-            println!();
-        }
-    }
-    println!();
+    let render = tilemap.render(|tile| match tile {
+        Tile::Empty => '.',
+        Tile::Square => '#',
+        Tile::Circle => 'O',
+    });
+    eprintln!("{render}");
 }
 
 fn get_total_load(tilemap: &VecMatrix<Tile>) -> usize {
@@ -197,10 +104,15 @@ pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
     let mut idx_to_load = HashMap::new();
 
     let loop_idx = loop {
-        tilt_north(&mut tilemap);
-        tilt_west(&mut tilemap);
-        tilt_south(&mut tilemap);
-        tilt_east(&mut tilemap);
+        // North, west, south and east
+        for orientation in [
+            Orientation::IDENTITY,
+            Orientation::TRANSPOSED,
+            Orientation::FLIPPED_V,
+            Orientation::ROTATED_CCW,
+        ] {
+            tilt(&mut tilemap.view_mut(orientation));
+        }
 
         i += 1;
 
@@ -220,3 +132,19 @@ pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
 
     Ok((0, cycle_total_load))
 }
+
+aoc_common::examples! {
+    solution: get_answer;
+    example: "
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+" => _, 64;
+}
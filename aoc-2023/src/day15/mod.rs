@@ -111,3 +111,8 @@ pub fn focus_lenses(
 
     Ok((hash_sum, total_lense_sum))
 }
+
+aoc_common::examples! {
+    solution: focus_lenses;
+    example: "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7" => 1320, 145;
+}
@@ -1,4 +1,4 @@
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
+use aoc_common::util::{self, Direction4 as Direction, MatrixIndex, VecMatrix};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Mirror {
@@ -32,24 +32,14 @@ impl From<char> for Tile {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Left,
-    Down,
-    Right,
-}
-
-impl Direction {
-    const fn bounce(self, mirror: Mirror) -> Self {
-        use Direction::{Down, Left, Right, Up};
-        use Mirror::{Backward, Forward};
-        match (self, mirror) {
-            (Left, Backward) | (Right, Forward) => Up,
-            (Up, Backward) | (Down, Forward) => Left,
-            (Right, Backward) | (Left, Forward) => Down,
-            (Down, Backward) | (Up, Forward) => Right,
-        }
+const fn bounce(direction: Direction, mirror: Mirror) -> Direction {
+    use Direction::{Down, Left, Right, Up};
+    use Mirror::{Backward, Forward};
+    match (direction, mirror) {
+        (Left, Backward) | (Right, Forward) => Up,
+        (Up, Backward) | (Down, Forward) => Left,
+        (Right, Backward) | (Left, Forward) => Down,
+        (Down, Backward) | (Up, Forward) => Right,
     }
 }
 
@@ -102,14 +92,7 @@ impl<'a> BeamMap<'a> {
     }
 
     fn add_next_beam(&mut self, beam: Beam, direction: Direction) {
-        let maybe_location = match direction {
-            Direction::Up => self.tilemap.next_up(beam.location),
-            Direction::Left => self.tilemap.next_left(beam.location),
-            Direction::Down => self.tilemap.next_down(beam.location),
-            Direction::Right => self.tilemap.next_right(beam.location),
-        };
-
-        if let Some(location) = maybe_location {
+        if let Some(location) = self.tilemap.step(beam.location, direction, 1) {
             let beam = Beam { location, direction };
             let beam_visits = &mut self.visits[beam.location];
             if !beam_visits.is_visited(beam.direction) {
@@ -134,7 +117,7 @@ impl<'a> BeamMap<'a> {
         for beam in std::mem::take(&mut self.beams) {
             match self.tilemap[beam.location] {
                 Tile::Empty => self.add_next_beam(beam, beam.direction),
-                Tile::Mirror(mirror) => self.add_next_beam(beam, beam.direction.bounce(mirror)),
+                Tile::Mirror(mirror) => self.add_next_beam(beam, bounce(beam.direction, mirror)),
                 Tile::Splitter(splitter) => {
                     use Direction::{Down, Left, Right, Up};
                     use Splitter::{Horizontal, Vertical};
@@ -192,16 +175,7 @@ fn count_visited(tilemap: &VecMatrix<Tile>, start_beam: Beam) -> usize {
 pub fn count_shining_tiles(
     lines: impl Iterator<Item = String>,
 ) -> util::GenericResult<(usize, usize)> {
-    let tilemap = {
-        let mut width = 0;
-        let mut data = vec![];
-
-        for line in lines {
-            width = line.len();
-            data.extend(line.chars().map(Tile::from));
-        }
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(lines)?;
 
     let top_left_visits = count_visited(
         &tilemap,
@@ -218,3 +192,8 @@ pub fn count_shining_tiles(
 
     Ok((top_left_visits, max_visits))
 }
+
+aoc_common::examples! {
+    solution: count_shining_tiles;
+    example: file("day16-1.txt") => 46, 51;
+}
@@ -248,3 +248,22 @@ pub fn find_shortest_paths(lines: impl Iterator<Item = String>) -> util::Generic
 
     Ok((min_path_short, min_path_long))
 }
+
+aoc_common::examples! {
+    solution: find_shortest_paths;
+    example: "
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+" => 102, 94;
+}
@@ -188,7 +188,10 @@ pub fn dig_lagoon(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
         moves_data.push((direction, moves));
 
         lexer.literal("(#")?;
-        let moves = usize::from_str_radix(lexer.take(5)?, 16)?;
+        // All 5 digits have to be hex, not just a prefix of them
+        let mut colour = util::Lexer::of(lexer.take(5)?);
+        let moves = colour.hex_number()?;
+        colour.end()?;
         let direction = Direction::from_digit(lexer.symbol()?);
         lexer.literal(")")?;
         lexer.end()?;
@@ -201,3 +204,23 @@ pub fn dig_lagoon(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
 
     Ok((area, area_colored))
 }
+
+aoc_common::examples! {
+    solution: dig_lagoon;
+    example: "
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+" => 62, 952_408_144_115;
+}
@@ -1,6 +1,9 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
-use aoc_common::util;
+use aoc_common::{
+    Day,
+    util::{self, iter::IteratorExtended},
+};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Category {
@@ -55,7 +58,7 @@ impl From<&str> for Transition {
     }
 }
 
-struct Detail {
+pub struct Detail {
     rating: [usize; 4],
 }
 
@@ -159,7 +162,7 @@ impl Rule {
     }
 }
 
-struct Workflow {
+pub struct Workflow {
     rules: Vec<Rule>,
 }
 
@@ -220,100 +223,139 @@ fn do_accepted_range_size(
     total
 }
 
-pub fn find_ratings(
-    mut lines: impl Iterator<Item = String>,
-) -> util::GenericResult<(usize, usize)> {
-    let mut workflows: HashMap<String, Workflow> = HashMap::new();
+pub struct System {
+    pub workflows: HashMap<String, Workflow>,
+    pub details: Vec<Detail>,
+}
 
-    for line in lines.by_ref().take_while(|s| !s.is_empty()) {
-        let mut lexer = util::Lexer::of(&line);
-        let label = lexer.before_literal("{")?.to_owned();
+impl System {
+    fn workflow(&self, label: &str) -> &Workflow {
+        self.workflows
+            .get(label)
+            .expect("All workflows should be defined")
+    }
+}
 
-        let rules_str = lexer.before_literal("}")?;
-        lexer.end()?;
+pub struct Aplenty;
 
-        let mut rules = vec![];
-        for rule_str in rules_str.split(',') {
-            let rule = if rule_str.contains(':') {
-                let mut lexer = util::Lexer::of(rule_str);
+impl Day for Aplenty {
+    type Input = System;
 
-                let condition = Condition {
-                    category: Category::from(lexer.symbol()?),
-                    operation: Operation::from(lexer.symbol()?),
-                    value: lexer.unsigned_number()?,
-                };
+    fn parse(lines: impl Iterator<Item = String>) -> util::GenericResult<Self::Input> {
+        let mut workflows: HashMap<String, Workflow> = HashMap::new();
+        let mut sections = lines.sections();
 
-                lexer.literal(":")?;
+        for line in sections.section().ok_or("Workflows are missing")? {
+            let mut lexer = util::Lexer::of(&line);
+            let label = lexer.before_literal("{")?.to_owned();
 
-                let transition = Transition::from(lexer.take_rest()?);
+            let rules_str = lexer.before_literal("}")?;
+            lexer.end()?;
 
-                Rule {
-                    condition: Some(condition),
-                    transition,
-                }
-            } else {
-                Rule {
-                    condition: None,
-                    transition: Transition::from(rule_str),
-                }
-            };
+            let mut rules = vec![];
+            for rule_str in rules_str.split(',') {
+                let rule = if rule_str.contains(':') {
+                    let mut lexer = util::Lexer::of(rule_str);
+
+                    let condition = Condition {
+                        category: Category::from(lexer.symbol()?),
+                        operation: Operation::from(lexer.symbol()?),
+                        value: lexer.unsigned_number()?,
+                    };
+
+                    lexer.literal(":")?;
+
+                    let transition = Transition::from(lexer.take_rest()?);
+
+                    Rule {
+                        condition: Some(condition),
+                        transition,
+                    }
+                } else {
+                    Rule {
+                        condition: None,
+                        transition: Transition::from(rule_str),
+                    }
+                };
 
-            rules.push(rule);
-        }
+                rules.push(rule);
+            }
 
-        workflows.insert(label, Workflow { rules });
-    }
+            workflows.insert(label, Workflow { rules });
+        }
 
-    let mut details = vec![];
-    for line in lines {
-        let mut lexer = util::Lexer::of(&line);
-        lexer.literal("{")?;
-        let detail_str = lexer.before_literal("}")?;
-        lexer.end()?;
-
-        let mut detail = Detail::new();
-        for rank_str in detail_str.split(',') {
-            let mut lexer = util::Lexer::of(rank_str);
-            let category = Category::from(lexer.symbol()?);
-            lexer.literal("=")?;
-            let rank = lexer.unsigned_number()?;
+        let mut details = vec![];
+        for line in sections.section().ok_or("Parts are missing")? {
+            let mut lexer = util::Lexer::of(&line);
+            lexer.literal("{")?;
+            let detail_str = lexer.before_literal("}")?;
             lexer.end()?;
 
-            *detail.get_rating_mut(category) = rank;
+            let mut detail = Detail::new();
+            for rank_str in detail_str.split(',') {
+                let mut lexer = util::Lexer::of(rank_str);
+                let category = Category::from(lexer.symbol()?);
+                lexer.literal("=")?;
+                let rank = lexer.unsigned_number()?;
+                lexer.end()?;
+
+                *detail.get_rating_mut(category) = rank;
+            }
+
+            details.push(detail);
         }
 
-        details.push(detail);
+        Ok(System { workflows, details })
     }
 
-    let mut accepted_sum = 0;
-    for detail in details {
-        let mut current_workflow = workflows
-            .get("in")
-            .expect("All workflows should be defined");
-
-        loop {
-            match current_workflow.get_transition(&detail) {
-                Transition::Next(next) => {
-                    current_workflow = workflows
-                        .get(next)
-                        .expect("All workflows should be defined");
+    fn part1(system: &Self::Input) -> util::GenericResult<impl Display> {
+        let mut accepted_sum = 0;
+        for detail in &system.details {
+            let mut current_workflow = system.workflow("in");
+
+            loop {
+                match current_workflow.get_transition(detail) {
+                    Transition::Next(next) => current_workflow = system.workflow(next),
+                    Transition::Accept => {
+                        accepted_sum += detail.total_rank();
+                        break;
+                    }
+                    Transition::Reject => break,
                 }
-                Transition::Accept => {
-                    accepted_sum += detail.total_rank();
-                    break;
-                }
-                Transition::Reject => break,
             }
         }
+
+        Ok(accepted_sum)
     }
 
-    let total_size = do_accepted_range_size(
-        &workflows,
-        workflows
-            .get("in")
-            .expect("All workflows should be defined"),
-        DetailRange::new(),
-    );
+    fn part2(system: &Self::Input) -> util::GenericResult<impl Display> {
+        Ok(do_accepted_range_size(
+            &system.workflows,
+            system.workflow("in"),
+            DetailRange::new(),
+        ))
+    }
+}
 
-    Ok((accepted_sum, total_size))
+aoc_common::examples! {
+    day: Aplenty;
+    example: "
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+" => 19114, 167_409_079_868_000;
 }
@@ -1,4 +1,4 @@
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
+use aoc_common::util::{self, CharParseError, MatrixIndex, Point, SparseGrid, VecMatrix};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -7,13 +7,15 @@ enum Tile {
     Rock,
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = CharParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'S' => Self::Start,
-            '.' => Self::Plot,
-            '#' => Self::Rock,
-            _ => panic!("Unknown tile char {value}"),
+            'S' => Ok(Self::Start),
+            '.' => Ok(Self::Plot),
+            '#' => Ok(Self::Rock),
+            ch => Err(CharParseError::new("Tile ('S', '.' or '#')", ch)),
         }
     }
 }
@@ -53,70 +55,125 @@ fn count_tiles(tilemap: &VecMatrix<Tile>, start: MatrixIndex, steps: usize, odd:
     tile_count
 }
 
+// Plots reachable in exactly `steps` for each of `targets` (ascending), when the garden is repeated
+// infinitely in every direction
+fn count_tiled_plots<const N: usize>(
+    tilemap: &VecMatrix<Tile>,
+    start: MatrixIndex,
+    targets: [usize; N],
+) -> [usize; N] {
+    let start = Point::from(start);
+    let mut visited = SparseGrid::new();
+    visited.insert(start, ());
+
+    let mut front = vec![start];
+    // Plots reached on even and odd steps, a plot stays reachable every other step
+    let mut parity_counts = [1, 0];
+    let mut counts = [0; N];
+
+    for step in 1..=targets.into_iter().max().unwrap_or(0) {
+        for point in std::mem::take(&mut front) {
+            for neighbour in point.neighbours4() {
+                if tilemap.get_wrapped(neighbour) != Some(&Tile::Rock)
+                    && !visited.contains(neighbour)
+                {
+                    visited.insert(neighbour, ());
+                    front.push(neighbour);
+                }
+            }
+        }
+
+        parity_counts[step % 2] += front.len();
+        for (&target, count) in targets.iter().zip(&mut counts) {
+            if target == step {
+                *count = parity_counts[step % 2];
+            }
+        }
+    }
+
+    counts
+}
+
 pub fn count_garden_steps(
     lines: impl Iterator<Item = String>,
 ) -> util::GenericResult<(usize, usize)> {
-    let tilemap = {
-        let mut data = vec![];
-        let mut width = 0;
-        for line in lines {
-            width = line.len();
-            data.extend(line.chars().map(Tile::from));
-        }
+    const STEPS: usize = 26_501_365;
 
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(lines)?;
 
     let start_idx = tilemap
         .iter_enumerate()
         .find_map(|(idx, tile)| (*tile == Tile::Start).then_some(idx))
         .expect("Start tile should exist");
 
-    println!(
-        "- Full odd:   {}",
-        count_tiles(&tilemap, start_idx, 290, true)
-    );
-    println!(
-        "- Full even:  {}",
-        count_tiles(&tilemap, start_idx, 290, false)
+    // The reachable area grows by the same pattern every time it crosses one more copy of the
+    // garden, so the plot count is quadratic in the number of copies crossed
+    let size = tilemap.width();
+    let center = MatrixIndex { row: size / 2, col: size / 2 };
+    aoc_common::assume!(
+        tilemap.height() == size && start_idx == center && STEPS % size == size / 2,
+        "the garden is a square with the start in the middle, and the steps end on its edge"
     );
 
-    let corners = [
-        MatrixIndex { row: 0, col: 0 },
-        MatrixIndex { row: 0, col: tilemap.width() - 1 },
-        MatrixIndex { row: tilemap.height() - 1, col: 0 },
-        MatrixIndex {
-            row: tilemap.height() - 1,
-            col: tilemap.width() - 1,
-        },
-    ];
-
-    for corner in corners {
-        println!("Corner [{}, {}]:", corner.row, corner.col);
-        // println!("- Small odd:  {}", count_tiles(&tilemap, corner, 64, true));
-        println!("- Small even: {}", count_tiles(&tilemap, corner, 64, false));
-        println!("- Big odd:    {}", count_tiles(&tilemap, corner, 195, true));
-        // println!("- Big even:   {}", count_tiles(&tilemap, corner, 195, false));
-    }
+    let counts = count_tiled_plots(&tilemap, start_idx, [0, 1, 2].map(|n| size / 2 + n * size));
+    // Signed, nothing says the differences of the counts are positive
+    let [a, b, c] =
+        counts.map(|count| i64::try_from(count).expect("Plot count should fit into i64"));
+    let n = i64::try_from(STEPS / size)?;
+    let tiled_count = a + n * (b - a) + n * (n - 1) / 2 * (c - 2 * b + a);
+
+    Ok((
+        count_tiles(&tilemap, start_idx, 64, false),
+        usize::try_from(tiled_count)?,
+    ))
+}
 
-    let sides = [
-        MatrixIndex { row: 0, col: tilemap.width() / 2 },
-        MatrixIndex {
-            row: tilemap.height() - 1,
-            col: tilemap.width() / 2,
-        },
-        MatrixIndex { row: tilemap.height() / 2, col: 0 },
-        MatrixIndex {
-            row: tilemap.height() / 2,
-            col: tilemap.width() - 1,
-        },
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::util::{MatrixIndex, Point, VecMatrix};
+
+    use super::{Tile, count_tiled_plots};
+
+    const GARDEN: [&str; 11] = [
+        "...........",
+        ".....###.#.",
+        ".###.##..#.",
+        "..#.#...#..",
+        "....#.#....",
+        ".##..S####.",
+        ".##..#...#.",
+        ".......##..",
+        ".##.#.####.",
+        ".##..##.##.",
+        "...........",
     ];
 
-    for side in sides {
-        println!("Side [{}, {}]:", side.row, side.col);
-        // println!("- Big odd:    {}", count_tiles(&tilemap, side, 195, true));
-        println!("- Big even:   {}", count_tiles(&tilemap, side, 130, false));
+    // Every plot the elf can stand on after each step, kept as a set
+    fn brute_force(tilemap: &VecMatrix<Tile>, start: MatrixIndex, steps: usize) -> usize {
+        let mut plots = HashSet::from([Point::from(start)]);
+        for _ in 0..steps {
+            plots = plots
+                .iter()
+                .flat_map(|point| point.neighbours4())
+                .filter(|&point| tilemap.get_wrapped(point) != Some(&Tile::Rock))
+                .collect();
+        }
+        plots.len()
     }
 
-    Ok((count_tiles(&tilemap, start_idx, 64, false), 0))
+    #[test]
+    fn tiled_plots() {
+        let lines = GARDEN.into_iter().map(String::from);
+        let tilemap = VecMatrix::parse_lines(lines).unwrap();
+        let start = MatrixIndex { row: 5, col: 5 };
+
+        let targets = [6, 10, 27, 50];
+        assert_eq!(
+            count_tiled_plots(&tilemap, start, targets),
+            targets.map(|steps| brute_force(&tilemap, start, steps))
+        );
+        assert_eq!(count_tiled_plots(&tilemap, start, [50, 100]), [1594, 6536]);
+    }
 }
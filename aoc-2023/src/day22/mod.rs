@@ -158,3 +158,16 @@ pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
 
     Ok((safe_to_remove, falling_total))
 }
+
+aoc_common::examples! {
+    solution: get_answer;
+    example: "
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+" => 5, 7;
+}
@@ -1,25 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
-
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    North,
-    West,
-    South,
-    East,
-}
-
-impl Direction {
-    const fn opposite(self) -> Self {
-        match self {
-            Self::North => Self::South,
-            Self::West => Self::East,
-            Self::South => Self::North,
-            Self::East => Self::West,
-        }
-    }
-}
+use aoc_common::util::{self, Direction4 as Direction, MatrixIndex, VecMatrix};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -33,28 +14,15 @@ impl From<char> for Tile {
         match value {
             '.' => Self::Path,
             '#' => Self::Forest,
-            '^' => Self::Slope(Direction::North),
-            '<' => Self::Slope(Direction::West),
-            'v' => Self::Slope(Direction::South),
-            '>' => Self::Slope(Direction::East),
+            '^' => Self::Slope(Direction::Up),
+            '<' => Self::Slope(Direction::Left),
+            'v' => Self::Slope(Direction::Down),
+            '>' => Self::Slope(Direction::Right),
             _ => panic!("Unknown tile {value}"),
         }
     }
 }
 
-const fn next_idx(
-    tilemap: &VecMatrix<Tile>,
-    idx: MatrixIndex,
-    direction: Direction,
-) -> Option<MatrixIndex> {
-    match direction {
-        Direction::North => tilemap.next_up(idx),
-        Direction::West => tilemap.next_left(idx),
-        Direction::South => tilemap.next_down(idx),
-        Direction::East => tilemap.next_right(idx),
-    }
-}
-
 struct Edge {
     to: MatrixIndex,
     length: usize,
@@ -79,7 +47,7 @@ fn create_edge(
     let mut length = 0;
 
     'outer: loop {
-        if let Some(next) = next_idx(tilemap, current, direction) {
+        if let Some(next) = tilemap.step(current, direction, 1) {
             current = next;
         } else {
             return None;
@@ -93,17 +61,12 @@ fn create_edge(
         }
 
         let mut next_direction = None;
-        for dir in [
-            Direction::North,
-            Direction::West,
-            Direction::South,
-            Direction::East,
-        ] {
+        for dir in Direction::ALL {
             if dir == direction.opposite() {
                 continue;
             }
 
-            if let Some(idx) = next_idx(tilemap, current, dir) {
+            if let Some(idx) = tilemap.step(current, dir, 1) {
                 if tilemap[idx] != Tile::Forest && next_direction.replace(dir).is_some() {
                     // Multiple directions to continue => next vertex is reached
                     break 'outer;
@@ -128,12 +91,7 @@ fn create_edge(
 fn create_vertex(tilemap: &VecMatrix<Tile>, start: MatrixIndex) -> Vertex {
     let mut vertex = Vertex::new();
 
-    for direction in [
-        Direction::North,
-        Direction::West,
-        Direction::South,
-        Direction::East,
-    ] {
+    for direction in Direction::ALL {
         if let Some(edge) = create_edge(tilemap, start, direction) {
             vertex.edges.push(edge);
         }
@@ -202,17 +160,7 @@ fn find_max_path(tilemap: &VecMatrix<Tile>, start: MatrixIndex, end: MatrixIndex
 pub fn find_longest_path(
     lines: impl Iterator<Item = String>,
 ) -> util::GenericResult<(usize, usize)> {
-    let tilemap = {
-        let mut data = vec![];
-        let mut width = 0;
-
-        for line in lines {
-            width = line.len();
-            data.extend(line.chars().map(Tile::from));
-        }
-
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(lines)?;
 
     let start = MatrixIndex { row: 0, col: 1 };
     let end = MatrixIndex {
@@ -237,3 +185,32 @@ pub fn find_longest_path(
 
     Ok((max_path, max_path_no_slopes))
 }
+
+aoc_common::examples! {
+    solution: find_longest_path;
+    example: "
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+" => 94, 154;
+}
@@ -1,4 +1,4 @@
-use aoc_common::util;
+use aoc_common::{scan, util};
 
 #[derive(Default)]
 struct Coord {
@@ -102,31 +102,13 @@ fn check_intersects_xy(first: &Stone, second: &Stone) -> bool {
 pub fn magic_collisions(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, i64)> {
     let stones = lines
         .map(|line| {
-            let mut lexer = util::Lexer::of(&line);
-            let mut stone = Stone::default();
-
-            lexer
-                .chain()
-                .number(&mut stone.start.x)?
-                .literal(",")?
-                .whitespace()?
-                .number(&mut stone.start.y)?
-                .literal(",")?
-                .whitespace()?
-                .number(&mut stone.start.z)?
-                .whitespace()?
-                .literal("@")?
-                .whitespace()?
-                .number(&mut stone.velocity.x)?
-                .literal(",")?
-                .whitespace()?
-                .number(&mut stone.velocity.y)?
-                .literal(",")?
-                .whitespace()?
-                .number(&mut stone.velocity.z)?
-                .end()?;
-
-            Ok(stone)
+            let (x, y, z, dx, dy, dz) =
+                scan!(&line, "{}, {}, {} @ {}, {}, {}" => (i64, i64, i64, i64, i64, i64))?;
+
+            Ok(Stone {
+                start: Coord { x, y, z },
+                velocity: Coord { x: dx, y: dy, z: dz },
+            })
         })
         .collect::<util::lexer::Result<Vec<_>>>()?;
 
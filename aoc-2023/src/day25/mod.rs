@@ -36,10 +36,13 @@ pub fn disconnect_nodes(
         let mut lexer = util::Lexer::of(&line);
         let left = lexer.take_while(|ch| ch.is_ascii_alphabetic())?;
         lexer.literal(":")?;
-        while lexer.end().is_err() {
-            lexer.whitespace()?;
-            let right = lexer.take_while(|ch| ch.is_ascii_alphabetic())?;
+        let rights = lexer.many1(|lexer| {
+            lexer.literal(" ")?;
+            lexer.take_while(|ch| ch.is_ascii_alphabetic())
+        })?;
+        lexer.end()?;
 
+        for right in rights {
             // println!("    {} -- {}", left, right);
             graph
                 .entry(left.to_string())
@@ -81,3 +81,19 @@ pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
 
     Ok((part_numbers_sum, gear_ratio_sum))
 }
+
+aoc_common::examples! {
+    solution: get_answer;
+    example: "
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+" => 4361, 467_835;
+}
@@ -1,4 +1,4 @@
-use aoc_common::util;
+use aoc_common::util::{self, iter::IteratorExtended};
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
 struct Range {
@@ -135,7 +135,7 @@ impl IntegerSet {
         ranges.sort_by_key(Range::start);
         let strings = ranges.iter().map(|r| format!("{r}")).collect::<Vec<_>>();
 
-        println!("{}: [{}]", prefix, strings.join(", "));
+        eprintln!("{}: [{}]", prefix, strings.join(", "));
     }
 
     fn iter_ranges(&self) -> impl Iterator<Item = &Range> {
@@ -151,15 +151,17 @@ fn parse_seeds(line: &str) -> util::lexer::Result<(Vec<usize>, IntegerSet)> {
     let mut lexer = util::Lexer::of(line);
     lexer.literal("seeds:")?;
 
-    let mut values = Vec::new();
-    let mut ranges = IntegerSet::new();
-
-    while lexer.end().is_err() {
+    let pairs = lexer.many1(|lexer| {
         lexer.whitespace()?;
         let val1 = lexer.unsigned_number()?;
         lexer.whitespace()?;
-        let val2 = lexer.unsigned_number()?;
+        Ok((val1, lexer.unsigned_number()?))
+    })?;
+    lexer.end()?;
 
+    let mut values = Vec::new();
+    let mut ranges = IntegerSet::new();
+    for (val1, val2) in pairs {
         values.extend([val1, val2]);
         ranges.insert(Range::new(val1, val2));
     }
@@ -167,12 +169,26 @@ fn parse_seeds(line: &str) -> util::lexer::Result<(Vec<usize>, IntegerSet)> {
     Ok((values, ranges))
 }
 
-pub fn find_locations(
-    mut lines: impl Iterator<Item = String>,
-) -> util::GenericResult<(usize, usize)> {
-    let (mut values, mut ranges) = parse_seeds(&lines.next().expect("Seed line is expected"))?;
+fn parse_mapping(line: &str) -> util::lexer::Result<(Range, Range)> {
+    let mut lexer = util::Lexer::of(line);
 
-    let _ = lines.next().expect("Input ended too soon");
+    let dest_start = lexer.unsigned_number::<usize>()?;
+    lexer.whitespace()?;
+    let source_start = lexer.unsigned_number::<usize>()?;
+    lexer.whitespace()?;
+    let range_len = lexer.unsigned_number::<usize>()?;
+    lexer.whitespace()?;
+
+    Ok((
+        Range::new(source_start, range_len),
+        Range::new(dest_start, range_len),
+    ))
+}
+
+pub fn find_locations(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
+    let mut sections = lines.sections();
+    let (mut values, mut ranges) =
+        sections.parse(|section| Ok(parse_seeds(&section.next().unwrap_or_default())?))?;
 
     let maps = [
         "seed-to-soil",
@@ -185,33 +201,22 @@ pub fn find_locations(
     ];
 
     for map in maps {
-        util::Lexer::of(&lines.next().expect("Input ended too soon"))
-            .chain()
-            .literal(map)?
-            .literal(" map:")?
-            .end()?;
+        let ((), mappings) = sections.header_body(
+            |header| {
+                util::Lexer::of(header)
+                    .chain()
+                    .literal(map)?
+                    .literal(" map:")?
+                    .end()?;
+                Ok(())
+            },
+            |line| Ok(parse_mapping(line)?),
+        )?;
 
         let mut new_values = values.clone();
         let mut new_ranges = IntegerSet::new();
 
-        loop {
-            let data = match lines.next() {
-                None => break,
-                Some(s) if s.is_empty() => break,
-                Some(s) => s,
-            };
-            let mut lexer = util::Lexer::of(&data);
-
-            let dest_start = lexer.unsigned_number::<usize>()?;
-            lexer.whitespace()?;
-            let source_start = lexer.unsigned_number::<usize>()?;
-            lexer.whitespace()?;
-            let range_len = lexer.unsigned_number::<usize>()?;
-            lexer.whitespace()?;
-
-            let source_range = Range::new(source_start, range_len);
-            let dest_range = Range::new(dest_start, range_len);
-
+        for (source_range, dest_range) in mappings {
             for (i, value) in values.iter().enumerate() {
                 if source_range.contains(*value) {
                     new_values[i] = dest_range.start() + (value - source_range.start());
@@ -249,3 +254,42 @@ pub fn find_locations(
 
     Ok((min_location, min_range))
 }
+
+aoc_common::examples! {
+    solution: find_locations;
+    example: "
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+" => 35, 46;
+}
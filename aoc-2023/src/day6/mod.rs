@@ -59,3 +59,11 @@ pub fn get_answer(mut lines: impl Iterator<Item = String>) -> util::GenericResul
 
     Ok((total_ways, total_ways_merged))
 }
+
+aoc_common::examples! {
+    solution: get_answer;
+    example: "
+Time:      7  15   30
+Distance:  9  40  200
+" => 288, 71503;
+}
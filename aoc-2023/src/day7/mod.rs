@@ -147,3 +147,14 @@ pub fn play_poker(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
 
     Ok((winnings_plain, winnings_joker))
 }
+
+aoc_common::examples! {
+    solution: play_poker;
+    example: "
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+" => 6440, 5905;
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use aoc_common::util;
+use aoc_common::util::{self, Interner};
+
+const AAA: usize = 0;
+const ZZZ: usize = 1;
 
 #[derive(Clone, Copy)]
 enum Direction {
@@ -8,47 +11,32 @@ enum Direction {
     Right,
 }
 
+#[derive(Default)]
 struct Node {
     left_id: usize,
     right_id: usize,
 }
 
 struct NodeMap {
-    name_to_node: HashMap<String, usize>,
+    names: Interner,
     nodes: Vec<Node>,
 }
 
 impl NodeMap {
     fn new() -> Self {
         Self {
-            name_to_node: HashMap::new(),
+            names: Interner::with_names(["AAA", "ZZZ"]),
             nodes: Vec::new(),
         }
     }
 
-    fn get_or_create_node(&mut self, name: String) -> usize {
-        if let Some(id) = self.name_to_node.get(&name) {
-            *id
-        } else {
-            self.nodes.push(Node { left_id: 0, right_id: 0 });
-            self.name_to_node.insert(name, self.nodes.len() - 1);
-            self.nodes.len() - 1
-        }
-    }
-
-    fn add_node(&mut self, name: String, left: String, right: String) {
-        let node_id = self.get_or_create_node(name);
-        let left_id = self.get_or_create_node(left);
-        let right_id = self.get_or_create_node(right);
+    fn add_node(&mut self, node_id: usize, left_id: usize, right_id: usize) {
+        self.nodes.resize_with(self.names.len(), Node::default);
 
         self.nodes[node_id].left_id = left_id;
         self.nodes[node_id].right_id = right_id;
     }
 
-    fn get_id_by_name(&self, name: &str) -> Option<usize> {
-        self.name_to_node.get(name).copied()
-    }
-
     fn get_node_by_id(&self, id: usize) -> Option<&Node> {
         self.nodes.get(id)
     }
@@ -62,16 +50,30 @@ fn parse_direction(ch: char) -> Result<Direction, String> {
     }
 }
 
-fn get_node_period(mut node: usize, move_mapping: &[usize]) -> usize {
+// Checks the assumptions from the note in `count_steps` along the way
+fn get_node_period(
+    mut node: usize,
+    move_mapping: &[usize],
+    is_end: impl Fn(usize) -> bool,
+) -> util::GenericResult<usize> {
     let mut visited_nodes: HashMap<usize, usize> = HashMap::from([(node, 0)]);
     let mut current_step = 0;
 
     loop {
         current_step += 1;
+        let prev_node = node;
         node = move_mapping[node];
 
         if let Some(&visit) = visited_nodes.get(&node) {
-            break current_step - visit;
+            aoc_common::assume!(
+                visit == 1,
+                "every start loops back to the node it reaches after the first step"
+            );
+            aoc_common::assume!(
+                is_end(prev_node),
+                "the end node is reached right before looping back"
+            );
+            break Ok(current_step - visit);
         }
 
         visited_nodes.insert(node, current_step);
@@ -115,17 +117,11 @@ pub fn count_steps(mut lines: impl Iterator<Item = String>) -> util::GenericResu
     for line in lines {
         let mut lexer = util::Lexer::of(&line);
 
-        let name = lexer
-            .take_while(|ch| ch.is_ascii_alphanumeric())?
-            .to_owned();
+        let name = lexer.interned(&mut map.names)?;
         lexer.literal(" = (")?;
-        let left = lexer
-            .take_while(|ch| ch.is_ascii_alphanumeric())?
-            .to_owned();
+        let left = lexer.interned(&mut map.names)?;
         lexer.literal(", ")?;
-        let right = lexer
-            .take_while(|ch| ch.is_ascii_alphanumeric())?
-            .to_owned();
+        let right = lexer.interned(&mut map.names)?;
         lexer.literal(")")?;
         lexer.end()?;
 
@@ -166,21 +162,33 @@ pub fn count_steps(mut lines: impl Iterator<Item = String>) -> util::GenericResu
         })
         .collect();
 
-    let steps_count = get_node_period(
-        map.get_id_by_name("AAA")
-            .expect("Node AAA was not added into the map"),
-        &move_mapping,
-    );
+    let steps_count = get_node_period(AAA, &move_mapping, |id| id == ZZZ)?;
 
+    let is_end = |id| map.names[id].ends_with('Z');
     let all_steps_count = map
-        .name_to_node
+        .names
         .iter()
-        .filter_map(|(name, id)| name.ends_with('A').then_some(*id))
-        .map(|node| get_node_period(node, &move_mapping))
-        .fold(1, get_lcm);
+        .filter_map(|(id, name)| name.ends_with('A').then_some(id))
+        .map(|node| get_node_period(node, &move_mapping, is_end))
+        .try_fold(1, |lcm, period| period.map(|period| get_lcm(lcm, period)))?;
 
     Ok((
         steps_count * directions.len(),
         all_steps_count * directions.len(),
     ))
 }
+
+aoc_common::examples! {
+    solution: count_steps;
+    part1: "
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+" => 2, _;
+}
@@ -50,3 +50,12 @@ pub fn extrapolate_sequence(
 
     Ok((next_values_sum, prev_values_sum))
 }
+
+aoc_common::examples! {
+    solution: extrapolate_sequence;
+    example: "
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+" => 114, 2;
+}
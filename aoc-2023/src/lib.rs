@@ -35,3 +35,32 @@ pub mod day6;
 pub mod day7;
 pub mod day8;
 pub mod day9;
+
+aoc_common::register_year! {
+    year: 2023,
+    1 => day1::calibrate, "Day 1: Trebuchet?!";
+    2 => day2::play_cube_game, "Day 2: Cube Conundrum";
+    3 => day3::get_answer, "Day 3: Gear Ratios";
+    4 => day4::count_scratchcards, "Day 4: Scratchcards";
+    5 => day5::find_locations, "Day 5: If You Give A Seed A Fertilizer";
+    6 => day6::get_answer, "Day 6: DISPLAY NAME";
+    7 => day7::play_poker, "Day 7: Camel Cards";
+    8 => day8::count_steps, "Day 8: Haunted Wasteland";
+    9 => day9::extrapolate_sequence, "Day 9: Mirage Maintenance";
+    10 => day10::find_enclosing_loop, "Day 10: Pipe Maze";
+    11 => day11::get_answer, "Day 11: DISPLAY NAME";
+    12 => day12::count_possible_states, "Day 12: Hot Springs";
+    13 => day13::count_mirrors, "Day 13: Point of Incidence";
+    14 => day14::get_answer, "Day 14: DISPLAY NAME";
+    15 => day15::focus_lenses, "Day 15: Lens Library";
+    16 => day16::count_shining_tiles, "Day 16: The Floor Will Be Lava";
+    17 => day17::find_shortest_paths, "Day 17: Clumsy Crucible";
+    18 => day18::dig_lagoon, "Day 18: Lavaduct Lagoon";
+    19 => day(day19::Aplenty), "Day 19: Aplenty";
+    20 => day20::press_buttons, "Day 20: Pulse Propagation";
+    21 => day21::count_garden_steps, "Day 21: Step Counter";
+    22 => day22::get_answer, "Day 22: DISPLAY NAME";
+    23 => day23::find_longest_path, "Day 23: A Long Walk";
+    24 => day24::magic_collisions, "Day 24: Never Tell Me The Odds";
+    25 => day25::disconnect_nodes, "Day 25: Snowverload";
+}
@@ -102,17 +102,7 @@ fn get_region(
 }
 
 pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
-    let tilemap: VecMatrix<char> = {
-        let mut lines = lines.peekable();
-
-        let width = lines.by_ref().peek().map_or(0, String::len);
-        let data = lines
-            .flat_map(String::into_bytes)
-            .map(Into::into)
-            .collect::<Vec<_>>();
-
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<char> = VecMatrix::parse_lines(lines)?;
 
     let mut region_ids = VecMatrix::with_data(vec![0; tilemap.len()], tilemap.width());
     let mut regions_count = 1;
@@ -1,4 +1,6 @@
-use aoc_common::util;
+use std::fmt::Display;
+
+use aoc_common::{Day, util};
 
 const DELTA: i64 = 10_000_000_000_000;
 
@@ -76,32 +78,76 @@ fn solve(a_x: i64, a_y: i64, b_x: i64, b_y: i64, prize_x: i64, prize_y: i64) ->
     Some((a, b))
 }
 
-pub fn get_answer(mut lines: impl Iterator<Item = String>) -> util::GenericResult<(i64, i64)> {
-    let mut total = 0;
-    let mut total_with_delta = 0;
+pub struct Machine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl Machine {
+    fn cost(&self, delta: i64) -> Option<i64> {
+        let (a_x, a_y) = self.a;
+        let (b_x, b_y) = self.b;
+        let (prize_x, prize_y) = self.prize;
+
+        solve(a_x, a_y, b_x, b_y, prize_x + delta, prize_y + delta).map(|(a, b)| 3 * a + b)
+    }
+}
 
-    loop {
-        let line_a = lines.next().ok_or("Button A line is missing")?;
-        let (a_x, a_y) = parse_numbers(&line_a, "Button A")?;
+pub struct ClawContraption;
 
-        let line_b = lines.next().ok_or("Button B line is missing")?;
-        let (b_x, b_y) = parse_numbers(&line_b, "Button B")?;
+impl Day for ClawContraption {
+    type Input = Vec<Machine>;
 
-        let line_prize = lines.next().ok_or("Prize line is missing")?;
-        let (prize_x, prize_y) = parse_numbers(&line_prize, "Prize")?;
+    fn parse(mut lines: impl Iterator<Item = String>) -> util::GenericResult<Self::Input> {
+        let mut machines = vec![];
 
-        if let Some((a, b)) = solve(a_x, a_y, b_x, b_y, prize_x, prize_y) {
-            total += 3 * a + b;
-        }
+        loop {
+            let line_a = lines.next().ok_or("Button A line is missing")?;
+            let a = parse_numbers(&line_a, "Button A")?;
 
-        if let Some((a, b)) = solve(a_x, a_y, b_x, b_y, prize_x + DELTA, prize_y + DELTA) {
-            total_with_delta += 3 * a + b;
-        }
+            let line_b = lines.next().ok_or("Button B line is missing")?;
+            let b = parse_numbers(&line_b, "Button B")?;
 
-        if lines.next().is_none() {
-            break;
+            let line_prize = lines.next().ok_or("Prize line is missing")?;
+            let prize = parse_numbers(&line_prize, "Prize")?;
+
+            machines.push(Machine { a, b, prize });
+
+            if lines.next().is_none() {
+                break;
+            }
         }
+
+        Ok(machines)
+    }
+
+    fn part1(machines: &Self::Input) -> util::GenericResult<impl Display> {
+        Ok(machines.iter().filter_map(|m| m.cost(0)).sum::<i64>())
+    }
+
+    fn part2(machines: &Self::Input) -> util::GenericResult<impl Display> {
+        Ok(machines.iter().filter_map(|m| m.cost(DELTA)).sum::<i64>())
     }
+}
 
-    Ok((total, total_with_delta))
+aoc_common::examples! {
+    day: ClawContraption;
+    example: "
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+" => 480, 875_318_608_908;
 }
@@ -1,85 +1,55 @@
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
+use aoc_common::{
+    scan,
+    util::{self, MatrixIndex, Point, VecMatrix},
+};
 
 const WIDTH: usize = 101;
 const HEIGHT: usize = 103;
 
 struct Robot {
-    start: (usize, usize),
-    velocity: (usize, usize),
+    start: Point,
+    velocity: Point,
 }
 
 impl Robot {
-    const fn new(start: (usize, usize), velocity: (isize, isize)) -> Self {
-        let velocity = (
-            if velocity.0 < 0 {
-                WIDTH - velocity.0.unsigned_abs()
-            } else {
-                velocity.0 as usize
-            },
-            if velocity.1 < 0 {
-                HEIGHT - velocity.1.unsigned_abs()
-            } else {
-                velocity.1 as usize
-            },
-        );
-
-        Self { start, velocity }
-    }
-
-    const fn get_position(&self, seconds: usize) -> (usize, usize) {
-        (
-            (self.start.0 + seconds * self.velocity.0) % WIDTH,
-            (self.start.1 + seconds * self.velocity.1) % HEIGHT,
-        )
+    // Position on the plane made of copies of the floor, see `MatrixIndex::wrap`
+    fn get_position(&self, seconds: i64) -> Point {
+        self.start + Point::new(self.velocity.row * seconds, self.velocity.col * seconds)
     }
 }
 
 #[allow(unused)]
-fn draw(robots: &[Robot], seconds: usize) {
-    let mut matrix = VecMatrix::with_data(vec![0; WIDTH * HEIGHT], WIDTH);
-
+fn draw(robots: &[Robot], seconds: i64) {
+    let mut floor = VecMatrix::with_data(vec![0u32; WIDTH * HEIGHT], WIDTH);
     for robot in robots {
-        let (x, y) = robot.get_position(seconds);
-        matrix[MatrixIndex { row: y, col: x }] += 1;
+        *floor.get_wrapped_mut(robot.get_position(seconds)).unwrap() += 1;
     }
 
-    for (idx, &count) in matrix.iter_enumerate() {
-        if count == 0 {
-            print!(".");
-        } else {
-            print!("{count}");
-        }
-
-        if idx.col == matrix.width() - 1 {
-            println!();
-        }
-    }
-    println!("SECONDS: {seconds}");
+    let render = floor.render(|&count| match count {
+        0 => '.',
+        _ => char::from_digit(count.min(9), 10).unwrap_or('+'),
+    });
+    eprint!("{render}");
+    eprintln!("SECONDS: {seconds}");
 }
 
 pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
     let mut robots = vec![];
 
     for line in lines {
-        let mut lexer = util::Lexer::of(&line);
-
-        lexer.literal("p=")?;
-        let start_x: usize = lexer.unsigned_number()?;
-        lexer.literal(",")?;
-        let start_y: usize = lexer.unsigned_number()?;
-
-        lexer.literal(" v=")?;
-        let vel_x: isize = lexer.number()?;
-        lexer.literal(",")?;
-        let vel_y: isize = lexer.number()?;
-        lexer.end()?;
+        let (start_x, start_y, vel_x, vel_y) =
+            scan!(&line, "p={},{} v={},{}" => (i64, i64, i64, i64))?;
 
-        robots.push(Robot::new((start_x, start_y), (vel_x, vel_y)));
+        robots.push(Robot {
+            start: Point::new(start_y, start_x),
+            velocity: Point::new(vel_y, vel_x),
+        });
     }
 
     let mut quadrants = [0usize; 4];
     for robot in &robots {
-        let (x, y) = robot.get_position(100);
+        let (MatrixIndex { row: y, col: x }, _) =
+            MatrixIndex::wrap(robot.get_position(100), WIDTH, HEIGHT);
         if x == WIDTH / 2 || y == HEIGHT / 2 {
             continue;
         }
@@ -1,21 +1,6 @@
 use std::collections::HashSet;
 
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
-
-#[allow(dead_code)] // linter warns on fields, we use them only to print in Debug/Display impl
-#[derive(Debug)]
-pub struct CharParseError {
-    expected: String,
-    actual: char,
-}
-
-impl std::fmt::Display for CharParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
-    }
-}
-
-impl std::error::Error for CharParseError {}
+use aoc_common::util::{self, CharParseError, Direction4 as Direction, MatrixIndex, VecMatrix};
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
 enum Tile {
@@ -34,35 +19,7 @@ impl TryFrom<char> for Tile {
             'O' => Ok(Self::Box),
             '#' => Ok(Self::Wall),
             '@' => Ok(Self::Robot),
-            ch => Err(CharParseError {
-                expected: "Tile ('.', 'O', '#', '@')".to_string(),
-                actual: ch,
-            }),
-        }
-    }
-}
-
-#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl TryFrom<char> for Direction {
-    type Error = CharParseError;
-
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        match value {
-            '^' => Ok(Self::Up),
-            '>' => Ok(Self::Right),
-            'v' => Ok(Self::Down),
-            '<' => Ok(Self::Left),
-            v => Err(CharParseError {
-                expected: "Direction [^, >, v, <]".into(),
-                actual: v,
-            }),
+            ch => Err(CharParseError::new("Tile ('.', 'O', '#', '@')", ch)),
         }
     }
 }
@@ -104,25 +61,9 @@ impl Map {
         }
     }
 
-    const fn next_position(
-        &self,
-        position: MatrixIndex,
-        direction: Direction,
-    ) -> Option<MatrixIndex> {
-        match direction {
-            Direction::Up => self.tilemap.next_up(position),
-            Direction::Right => self.tilemap.next_right(position),
-            Direction::Down => self.tilemap.next_down(position),
-            Direction::Left => self.tilemap.next_left(position),
-        }
-    }
-
-    const fn next_position_checked(
-        &self,
-        position: MatrixIndex,
-        direction: Direction,
-    ) -> MatrixIndex {
-        self.next_position(position, direction)
+    fn next_position_checked(&self, position: MatrixIndex, direction: Direction) -> MatrixIndex {
+        self.tilemap
+            .step(position, direction, 1)
             .expect("Cannot move out of bounds")
     }
 
@@ -310,22 +251,17 @@ impl Map {
 
     #[allow(unused)]
     fn draw(&self) {
-        for (idx, tile) in self.tilemap.iter_enumerate() {
-            if self.robot_position == idx {
-                print!("@");
-            } else {
-                match tile {
-                    Tile::Empty => print!("."),
-                    Tile::Box => print!("O"),
-                    Tile::Wall => print!("#"),
-                    Tile::Robot => panic!("???"),
-                }
-            }
-
-            if idx.col == self.tilemap.width() - 1 {
-                println!();
-            }
-        }
+        let mut render = self.tilemap.render(|tile| match tile {
+            Tile::Empty => '.',
+            Tile::Box => 'O',
+            Tile::Wall => '#',
+            Tile::Robot => panic!("???"),
+        });
+
+        // +1 for the newline after each row
+        let robot = self.robot_position.row * (self.tilemap.width() + 1) + self.robot_position.col;
+        render.replace_range(robot..=robot, "@");
+        eprint!("{render}");
     }
 
     fn get_box_gps(&self) -> usize {
@@ -337,26 +273,14 @@ impl Map {
     }
 }
 
-pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
+pub fn get_answer(mut lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
     let (tilemap, movements) = {
-        let mut lines = lines.peekable();
-
-        let width = lines.by_ref().peek().map_or(0, String::len);
-        let data: Vec<Tile> = lines
-            .by_ref()
-            .take_while(|line| !line.is_empty())
-            .flat_map(String::into_bytes)
-            .map(|byte| byte as char)
-            .map(TryInto::try_into)
-            .collect::<Result<Vec<_>, _>>()?;
-
+        let tilemap: VecMatrix<Tile> = VecMatrix::parse_grid(&mut lines)?;
         let movements: Vec<Direction> = lines
-            .flat_map(String::into_bytes)
-            .map(|byte| byte as char)
-            .map(TryInto::try_into)
+            .flat_map(util::parse_chars)
             .collect::<Result<Vec<_>, _>>()?;
 
-        (VecMatrix::with_data(data, width), movements)
+        (tilemap, movements)
     };
 
     let mut map = Map::new(tilemap)?;
@@ -1,21 +1,6 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
-
-#[allow(dead_code)] // linter warns on fields, we use them only to print in Debug/Display impl
-#[derive(Debug)]
-pub struct CharParseError {
-    expected: String,
-    actual: char,
-}
-
-impl std::fmt::Display for CharParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
-    }
-}
-
-impl std::error::Error for CharParseError {}
+use aoc_common::util::{self, CharParseError, Direction4 as Direction, MatrixIndex, VecMatrix};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Tile {
@@ -33,22 +18,11 @@ impl TryFrom<char> for Tile {
             '#' => Ok(Self::Wall),
             'S' => Ok(Self::Start),
             'E' => Ok(Self::End),
-            ch => Err(CharParseError {
-                expected: "Tile ('.', '#', 'S' or 'E')".to_string(),
-                actual: ch,
-            }),
+            ch => Err(CharParseError::new("Tile ('.', '#', 'S' or 'E')", ch)),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 struct Point {
     idx: MatrixIndex,
@@ -57,41 +31,22 @@ struct Point {
 
 impl Point {
     fn try_forward(&self, tilemap: &VecMatrix<Tile>) -> Option<Self> {
-        let next_idx = match self.direction {
-            Direction::North => tilemap.next_up(self.idx),
-            Direction::East => tilemap.next_right(self.idx),
-            Direction::South => tilemap.next_down(self.idx),
-            Direction::West => tilemap.next_left(self.idx),
-        };
-
-        next_idx.map(|idx| Self { idx, direction: self.direction })
+        tilemap
+            .step(self.idx, self.direction, 1)
+            .map(|idx| Self { idx, direction: self.direction })
     }
 
     const fn left(&self) -> Self {
-        let next_direction = match self.direction {
-            Direction::North => Direction::West,
-            Direction::East => Direction::North,
-            Direction::South => Direction::East,
-            Direction::West => Direction::South,
-        };
-
         Self {
             idx: self.idx,
-            direction: next_direction,
+            direction: self.direction.turn_left(),
         }
     }
 
     const fn right(&self) -> Self {
-        let next_direction = match self.direction {
-            Direction::North => Direction::East,
-            Direction::East => Direction::South,
-            Direction::South => Direction::West,
-            Direction::West => Direction::North,
-        };
-
         Self {
             idx: self.idx,
-            direction: next_direction,
+            direction: self.direction.turn_right(),
         }
     }
 }
@@ -107,7 +62,7 @@ fn find_fastest_path(
 
     let start = Point {
         idx: start,
-        direction: Direction::East,
+        direction: Direction::Right,
     };
 
     let mut to_visit: BTreeMap<usize, Vec<(Point, Point)>> = BTreeMap::new();
@@ -159,16 +114,13 @@ fn find_fastest_path(
     let mut best_tiles = HashSet::new();
     let mut visited = HashSet::new();
     let mut to_add = vec![
+        Point { idx: end, direction: Direction::Up },
         Point {
             idx: end,
-            direction: Direction::North,
-        },
-        Point { idx: end, direction: Direction::East },
-        Point {
-            idx: end,
-            direction: Direction::South,
+            direction: Direction::Right,
         },
-        Point { idx: end, direction: Direction::West },
+        Point { idx: end, direction: Direction::Down },
+        Point { idx: end, direction: Direction::Left },
     ];
 
     while let Some(point) = to_add.pop() {
@@ -186,18 +138,7 @@ fn find_fastest_path(
 }
 
 pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
-    let tilemap: VecMatrix<Tile> = {
-        let mut lines = lines.peekable();
-
-        let width = lines.by_ref().peek().map_or(0, String::len);
-        let data = lines
-            .flat_map(String::into_bytes)
-            .map(|b| b as char)
-            .map(TryInto::try_into)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(lines)?;
 
     let start = tilemap
         .iter_enumerate()
@@ -2,7 +2,6 @@ use std::mem;
 
 use aoc_common::util;
 
-#[allow(dead_code)] // linter warns on fields, we use them only to print in Debug/Display impl
 #[derive(Debug)]
 pub struct ByteParseError {
     expected: String,
@@ -11,7 +10,7 @@ pub struct ByteParseError {
 
 impl std::fmt::Display for ByteParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+        write!(f, "expected {}, found {}", self.expected, self.actual)
     }
 }
 
@@ -131,12 +130,13 @@ impl Processor {
 }
 
 fn parse_register(line: &str, prefix: &str) -> util::lexer::Result<u64> {
-    let mut lexer = util::Lexer::of(line);
-    lexer.literal(prefix)?;
-    lexer.literal(": ")?;
-    let num = lexer.unsigned_number()?;
-    lexer.end()?;
-    Ok(num)
+    util::Lexer::of(line).context(prefix, |lexer| {
+        lexer.literal(prefix)?;
+        lexer.literal(": ")?;
+        let num = lexer.unsigned_number()?;
+        lexer.end()?;
+        Ok(num)
+    })
 }
 
 pub fn get_answer(mut lines: impl Iterator<Item = String>) -> util::GenericResult<(String, u64)> {
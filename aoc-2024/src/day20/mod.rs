@@ -1,19 +1,4 @@
-use aoc_common::util::{self, VecMatrix};
-
-#[allow(dead_code)] // linter warns on fields, we use them only to print in Debug/Display impl
-#[derive(Debug)]
-pub struct CharParseError {
-    expected: String,
-    actual: char,
-}
-
-impl std::fmt::Display for CharParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
-    }
-}
-
-impl std::error::Error for CharParseError {}
+use aoc_common::util::{self, CharParseError, VecMatrix};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Tile {
@@ -31,27 +16,13 @@ impl TryFrom<char> for Tile {
             '#' => Ok(Self::Wall),
             'S' => Ok(Self::Start),
             'E' => Ok(Self::End),
-            ch => Err(CharParseError {
-                expected: "Tile ('.', '#', 'S' or 'E')".to_string(),
-                actual: ch,
-            }),
+            ch => Err(CharParseError::new("Tile ('.', '#', 'S' or 'E')", ch)),
         }
     }
 }
 
 pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
-    let tilemap: VecMatrix<Tile> = {
-        let mut lines = lines.peekable();
-
-        let width = lines.by_ref().peek().map_or(0, String::len);
-        let data = lines
-            .flat_map(String::into_bytes)
-            .map(|b| b as char)
-            .map(TryInto::try_into)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(lines)?;
 
     let start = tilemap
         .iter_enumerate()
@@ -10,11 +10,11 @@ const fn get_next_number(num: u64) -> u64 {
     (num ^ (num * 2048)) % 16_777_216
 }
 
-pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(u64, u64)> {
+pub fn get_answer(input: &str) -> util::GenericResult<(u64, u64)> {
     let mut secrets_sum = 0;
     let mut all_prices: Vec<HashMap<Sequence, u64>> = vec![];
 
-    for line in lines {
+    for line in input.lines() {
         let mut num = line.parse()?;
         let mut prev_digit = num % 10;
 
@@ -51,3 +51,17 @@ pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(u
 
     Ok((secrets_sum, max_profit))
 }
+
+aoc_common::examples! {
+    text: get_answer;
+    example: "
+1
+10
+100
+2024" => 37_327_623, _;
+    changes: "
+1
+2
+3
+2024" => _, 23;
+}
@@ -1,9 +1,13 @@
 use std::{
     collections::HashMap,
+    fmt::Display,
     ops::{BitAnd, BitOr, BitXor},
 };
 
-use aoc_common::util;
+use aoc_common::{
+    Day,
+    util::{self, Interner, iter::IteratorExtended},
+};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum WireState {
@@ -88,33 +92,25 @@ struct Gate {
     kind: GateKind,
 }
 
-struct Device {
-    wires: Vec<String>,
+#[derive(Clone)]
+pub struct Device {
+    wires: Interner,
     wire_states: Vec<WireState>,
-    wire_name_to_id: HashMap<String, usize>,
     gates: HashMap<usize, Gate>,
 }
 
 impl Device {
     fn new() -> Self {
         Self {
-            wires: vec![],
+            wires: Interner::new(),
             wire_states: vec![],
-            wire_name_to_id: HashMap::new(),
             gates: HashMap::new(),
         }
     }
 
     fn get_or_insert(&mut self, name: &str) -> usize {
-        if let Some(&id) = self.wire_name_to_id.get(name) {
-            return id;
-        }
-
-        let id = self.wires.len();
-        self.wires.push(name.to_string());
-        self.wire_states.push(WireState::Off);
-        self.wire_name_to_id.insert(name.to_string(), id);
-
+        let id = self.wires.intern(name);
+        self.wire_states.resize(self.wires.len(), WireState::Off);
         id
     }
 
@@ -188,10 +184,10 @@ impl Device {
 }
 
 fn gate_matches(device: &Device, inputs: (&str, &str), wire_id: usize, kind: GateKind) -> bool {
-    let Some(&input1_id) = device.wire_name_to_id.get(inputs.0) else {
+    let Some(input1_id) = device.wires.get(inputs.0) else {
         return false;
     };
-    let Some(&input2_id) = device.wire_name_to_id.get(inputs.1) else {
+    let Some(input2_id) = device.wires.get(inputs.1) else {
         return false;
     };
 
@@ -241,7 +237,7 @@ fn verify_addition(device: &Device, bits: usize) {
 
     let top_carry = format!("z{}", bits - 1);
 
-    let mut carry = device.wire_name_to_id[&top_carry];
+    let mut carry = device.wires.get(&top_carry).unwrap();
     for bit in (1..(bits - 1)).rev() {
         let carry_name = &device.wires[carry];
         let carry_gate = device
@@ -276,7 +272,7 @@ fn verify_addition(device: &Device, bits: usize) {
             carry_gate.kind
         );
 
-        let result_id = device.wire_name_to_id.get(&format!("z{bit:02}")).unwrap();
+        let result_id = device.wires.get(&format!("z{bit:02}")).unwrap();
         assert!(
             gate_matches(
                 device,
@@ -284,7 +280,7 @@ fn verify_addition(device: &Device, bits: usize) {
                     &device.wires[prev_sum_gate.inputs.0],
                     &device.wires[prev_sum_gate.inputs.1]
                 ),
-                *result_id,
+                result_id,
                 GateKind::Xor
             ),
             "Prev carry sum #{bit} ({prev_sum_name}): its inputs are not Xor'ed to make a result z{bit:02}"
@@ -305,87 +301,95 @@ fn verify_addition(device: &Device, bits: usize) {
 
     // Add some trivial checks for bit 0.
     assert!(is_bit_carry(device, carry, 0));
-    assert!(is_bit_sum(
-        device,
-        *device.wire_name_to_id.get("z00").unwrap(),
-        0
-    ));
+    assert!(is_bit_sum(device, device.wires.get("z00").unwrap(), 0));
 }
 
-pub fn get_answer(mut lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, String)> {
-    let mut device = Device::new();
+pub struct CrossedWires;
 
-    for line in lines.by_ref().take_while(|s| !s.is_empty()) {
-        let mut lexer = util::Lexer::of(&line);
-        let name = lexer.before_literal(": ")?;
-        let state = lexer.unsigned_number::<u8>()?;
-        lexer.end()?;
+impl Day for CrossedWires {
+    type Input = Device;
 
-        device.add_input(name, state.try_into()?);
-    }
+    fn parse(lines: impl Iterator<Item = String>) -> util::GenericResult<Self::Input> {
+        let mut device = Device::new();
+        let mut sections = lines.sections();
 
-    for line in lines {
-        let mut lexer = util::Lexer::of(&line);
-        let input1 = lexer.before_literal(" ")?;
-        let gate = lexer.before_literal(" ")?;
-        let input2 = lexer.before_literal(" -> ")?;
-        let output = lexer.take_rest()?;
+        for line in sections.section().ok_or("Inputs are missing")? {
+            let mut lexer = util::Lexer::of(&line);
+            let name = lexer.before_literal(": ")?;
+            let state = lexer.unsigned_number::<u8>()?;
+            lexer.end()?;
 
-        device.add_gate(input1, input2, output, gate.try_into()?);
+            device.add_input(name, state.try_into()?);
+        }
+
+        for line in sections.section().ok_or("Gates are missing")? {
+            let mut lexer = util::Lexer::of(&line);
+            let input1 = lexer.before_literal(" ")?;
+            let gate = lexer.before_literal(" ")?;
+            let input2 = lexer.before_literal(" -> ")?;
+            let output = lexer.take_rest()?;
+
+            device.add_gate(input1, input2, output, gate.try_into()?);
+        }
+
+        Ok(device)
     }
 
-    device.send_signals();
+    fn part1(device: &Self::Input) -> util::GenericResult<impl Display> {
+        let mut device = device.clone();
+        device.send_signals();
 
-    let mut z_names = device
-        .wires
-        .iter()
-        .filter(|name| name.starts_with('z'))
-        .collect::<Vec<_>>();
+        let mut z_wires = device
+            .wires
+            .iter()
+            .filter(|(_, name)| name.starts_with('z'))
+            .collect::<Vec<_>>();
 
-    z_names.sort_unstable();
-    z_names.reverse();
+        z_wires.sort_unstable_by_key(|&(_, name)| std::cmp::Reverse(name));
 
-    // let bits = z_names.len();
+        let mut output = 0;
+        for (id, _) in z_wires {
+            let bit = usize::from(device.wire_states[id] == WireState::On);
 
-    let mut output = 0;
-    for name in z_names {
-        let id = device.wire_name_to_id.get(name).unwrap();
-        let bit = usize::from(device.wire_states[*id] == WireState::On);
+            output = output * 2 + bit;
+        }
 
-        output = output * 2 + bit;
+        Ok(output)
     }
 
-    // Calculated by uncommenting next line and continuously solving errors produced by it.
-    // verify_addition(&device, bits);
-    let mut bad_outputs = vec![];
+    fn part2(_device: &Self::Input) -> util::GenericResult<impl Display> {
+        // let bits = _device.wires.iter().filter(|name| name.starts_with('z')).count();
 
-    // Errors and solutions log:
+        // Calculated by uncommenting next line and continuously solving errors produced by it.
+        // verify_addition(_device, bits);
+        let mut bad_outputs = vec![];
 
-    // Carry #39 (tnc) gate kind is Xor, while Or is expected
-    // - Found a gate to satisfy is_bit_carry : y39 AND x39 -> rvd
-    // - "rvd" is an input of "z39", so swapped "tnc" and "z39"
-    bad_outputs.extend(["tnc", "z39"]);
+        // Errors and solutions log:
 
-    // Carry #35 (bwc): one of operands should be a result of (x35 And y35)
-    // - "bwc" gate: ftc OR fsq -> bwc
-    // - The gate which produces such result: y35 AND x35 -> dvb
-    // - "ftc" consumes "dvb" to produce prev carry sum, so "dvb" and "fsq" should be swapped
-    bad_outputs.extend(["dvb", "fsq"]);
+        // Carry #39 (tnc) gate kind is Xor, while Or is expected
+        // - Found a gate to satisfy is_bit_carry : y39 AND x39 -> rvd
+        // - "rvd" is an input of "z39", so swapped "tnc" and "z39"
+        bad_outputs.extend(["tnc", "z39"]);
 
-    // Prev carry sum #17 (fhg) gate kind is Or, while And is expected
-    // - Found a gate to satisfy is_bit_sum : x17 XOR y17 -> qjg
-    // - "qjg" is an input of "z17", so swapped "fhg" and "z17"
-    bad_outputs.extend(["fhg", "z17"]);
+        // Carry #35 (bwc): one of operands should be a result of (x35 And y35)
+        // - "bwc" gate: ftc OR fsq -> bwc
+        // - The gate which produces such result: y35 AND x35 -> dvb
+        // - "ftc" consumes "dvb" to produce prev carry sum, so "dvb" and "fsq" should be swapped
+        bad_outputs.extend(["dvb", "fsq"]);
 
-    // Carry #10 (fgb): one of operands should be a result of (x10 And y10)
-    // - "fgb" gate : sst OR vcf -> fgb
-    // - The gate which produces such result: x10 AND y10 -> z10
-    // - "sst" gate : skm AND kck -> sst, is a correct prev carry sum
-    // - Swapping "vcf" and "z10"
-    bad_outputs.extend(["vcf", "z10"]);
+        // Prev carry sum #17 (fhg) gate kind is Or, while And is expected
+        // - Found a gate to satisfy is_bit_sum : x17 XOR y17 -> qjg
+        // - "qjg" is an input of "z17", so swapped "fhg" and "z17"
+        bad_outputs.extend(["fhg", "z17"]);
 
-    bad_outputs.sort_unstable();
-    let bad_outputs_string = bad_outputs.join(",");
+        // Carry #10 (fgb): one of operands should be a result of (x10 And y10)
+        // - "fgb" gate : sst OR vcf -> fgb
+        // - The gate which produces such result: x10 AND y10 -> z10
+        // - "sst" gate : skm AND kck -> sst, is a correct prev carry sum
+        // - Swapping "vcf" and "z10"
+        bad_outputs.extend(["vcf", "z10"]);
 
-    Ok((output, bad_outputs_string))
+        bad_outputs.sort_unstable();
+        Ok(bad_outputs.join(","))
+    }
 }
@@ -1,4 +1,4 @@
-use aoc_common::util;
+use aoc_common::util::{self, iter::IteratorExtended};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Lock {
@@ -19,16 +19,14 @@ fn are_overlapping(lock: &Lock, key: &Key) -> bool {
     false
 }
 
-pub fn get_answer(mut lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, String)> {
+pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, String)> {
     let mut locks = vec![];
     let mut keys = vec![];
 
-    while let Some(first) = lines.next() {
-        let is_lock = first == "#####";
-        let mut pin_lines = [(); 5].map(|()| lines.next().expect("Pin line"));
-
-        let _ = lines.next(); // Last line in the pattern, safe to ignore
-        let _ = lines.next(); // Empty line delimiter between patterns
+    for pattern in lines.sections() {
+        let is_lock = pattern[0] == "#####";
+        // First and last lines in the pattern are only there to tell locks from keys
+        let mut pin_lines = pattern.get(1..6).ok_or("Pin lines expected")?.to_vec();
 
         let mut pins = [0usize; 5];
 
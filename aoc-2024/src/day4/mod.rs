@@ -1,56 +1,16 @@
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
-
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-enum Direction {
-    Up,
-    UpRight,
-    Right,
-    DownRight,
-    Down,
-    DownLeft,
-    Left,
-    UpLeft,
-}
-
-impl Direction {
-    const fn all() -> [Self; 8] {
-        [
-            Self::Up,
-            Self::UpRight,
-            Self::Right,
-            Self::DownRight,
-            Self::Down,
-            Self::DownLeft,
-            Self::Left,
-            Self::UpLeft,
-        ]
-    }
-}
+use aoc_common::util::{self, Direction8, MatrixIndex, VecMatrix};
 
-fn next_idx(
+fn check_xmas_word(
     matrix: &VecMatrix<char>,
-    idx: MatrixIndex,
-    direction: Direction,
-) -> Option<MatrixIndex> {
-    match direction {
-        Direction::Up => matrix.next_up(idx),
-        Direction::UpRight => matrix.next_up(idx).and_then(|i| matrix.next_right(i)),
-        Direction::Right => matrix.next_right(idx),
-        Direction::DownRight => matrix.next_down(idx).and_then(|i| matrix.next_right(i)),
-        Direction::Down => matrix.next_down(idx),
-        Direction::DownLeft => matrix.next_down(idx).and_then(|i| matrix.next_left(i)),
-        Direction::Left => matrix.next_left(idx),
-        Direction::UpLeft => matrix.next_up(idx).and_then(|i| matrix.next_left(i)),
-    }
-}
-
-fn check_xmas_word(matrix: &VecMatrix<char>, start_idx: MatrixIndex, direction: Direction) -> bool {
+    start_idx: MatrixIndex,
+    direction: Direction8,
+) -> bool {
     let letters = ['X', 'M', 'A', 'S'];
     let mut idx = start_idx;
 
     // skip(1): suppose 'X' is checked somewhere up the stack
     for letter in letters.into_iter().skip(1) {
-        if let Some(next) = next_idx(matrix, idx, direction) {
+        if let Some(next) = matrix.step(idx, direction, 1) {
             idx = next;
         } else {
             return false;
@@ -71,7 +31,7 @@ fn count_xmas_words(matrix: &VecMatrix<char>, idx: MatrixIndex) -> usize {
 
     let mut count = 0;
 
-    for direction in Direction::all() {
+    for direction in Direction8::ALL {
         count += usize::from(check_xmas_word(matrix, idx, direction));
     }
 
@@ -94,12 +54,12 @@ fn check_mas_cross(matrix: &VecMatrix<char>, idx: MatrixIndex) -> bool {
     }
 
     let cross_directions = [
-        Direction::UpRight,
-        Direction::DownRight,
-        Direction::DownLeft,
-        Direction::UpLeft,
+        Direction8::UpRight,
+        Direction8::DownRight,
+        Direction8::DownLeft,
+        Direction8::UpLeft,
     ];
-    let Some(cross_indices) = array_transpose(cross_directions.map(|d| next_idx(matrix, idx, d)))
+    let Some(cross_indices) = array_transpose(cross_directions.map(|d| matrix.step(idx, d, 1)))
     else {
         return false;
     };
@@ -115,17 +75,7 @@ fn check_mas_cross(matrix: &VecMatrix<char>, idx: MatrixIndex) -> bool {
 }
 
 pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
-    let matrix = {
-        let mut data = vec![];
-        let mut width = 0;
-
-        for line in lines {
-            width = line.len();
-            data.extend(line.chars());
-        }
-
-        VecMatrix::with_data(data, width)
-    };
+    let matrix: VecMatrix<char> = VecMatrix::parse_lines(lines)?;
 
     let total_xmas = matrix
         .iter_enumerate()
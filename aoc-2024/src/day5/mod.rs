@@ -1,6 +1,6 @@
 use std::{cmp::Ordering, collections::HashSet, str::FromStr};
 
-use aoc_common::util;
+use aoc_common::util::{self, iter::IteratorExtended};
 
 #[derive(PartialEq, Eq, Hash)]
 struct OrderingRule(u8, u8);
@@ -42,18 +42,21 @@ impl OrderingRules {
     }
 }
 
-pub fn order_updates(mut lines: impl Iterator<Item = String>) -> util::GenericResult<(u64, u64)> {
-    let comparator = lines
-        .by_ref()
-        .take_while(|s| !s.is_empty())
-        .map(|s| s.parse())
-        .collect::<Result<OrderingRules, _>>()?
+pub fn order_updates(lines: impl Iterator<Item = String>) -> util::GenericResult<(u64, u64)> {
+    let mut sections = lines.sections();
+    let comparator = sections
+        .parse(|rules| {
+            Ok(rules
+                .map(|s| s.parse())
+                .collect::<Result<OrderingRules, _>>()?)
+        })?
         .into_comparator();
 
     let mut sum_unchanged = 0;
     let mut sum_changed = 0;
 
-    for line in lines {
+    let updates = sections.section().ok_or("Updates are missing")?;
+    for line in updates {
         let mut pages = line
             .split(',')
             .map(str::parse)
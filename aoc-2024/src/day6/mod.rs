@@ -1,15 +1,13 @@
 mod data;
 
-use data::{Direction, Tile};
+use data::Tile;
 
-use aoc_common::util::{self, BitSet, MatrixIndex, VecMatrix};
-
-use std::convert::TryInto;
+use aoc_common::util::{self, BitSet, Direction4, MatrixIndex, VecMatrix};
 
 #[derive(Clone, Copy)]
 struct GuardPosition {
     coord: MatrixIndex,
-    direction: Direction,
+    direction: Direction4,
 }
 
 struct Guard {
@@ -42,17 +40,12 @@ impl Guard {
     }
 
     fn next_position(&self, position: GuardPosition) -> Option<GuardPosition> {
-        let next_coord = match position.direction {
-            Direction::Up => self.tilemap.next_up(position.coord)?,
-            Direction::Right => self.tilemap.next_right(position.coord)?,
-            Direction::Down => self.tilemap.next_down(position.coord)?,
-            Direction::Left => self.tilemap.next_left(position.coord)?,
-        };
+        let next_coord = self.tilemap.step(position.coord, position.direction, 1)?;
 
         let next_position = match self.tilemap[next_coord] {
             Tile::Obstruction => GuardPosition {
                 coord: position.coord,
-                direction: position.direction.clockwise(),
+                direction: position.direction.turn_right(),
             },
             _ => GuardPosition {
                 coord: next_coord,
@@ -101,18 +94,7 @@ impl Guard {
 }
 
 pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
-    let tilemap: VecMatrix<Tile> = {
-        let mut lines = lines.peekable();
-
-        let width = lines.by_ref().peek().map_or(0, String::len);
-        let data = lines
-            .flat_map(String::into_bytes)
-            .map(|byte| byte as char)
-            .map(TryInto::try_into)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(lines)?;
 
     let mut guard = Guard::new(tilemap)?;
     guard.record_moves();
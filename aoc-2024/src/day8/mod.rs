@@ -78,18 +78,7 @@ fn add_all_antinodes(
 }
 
 pub fn get_answer(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
-    let tilemap: VecMatrix<Tile> = {
-        let mut lines = lines.peekable();
-
-        let width = lines.by_ref().peek().map_or(0, String::len);
-        let data = lines
-            .flat_map(String::into_bytes)
-            .map(|byte| byte as char)
-            .map(Into::into)
-            .collect::<Vec<_>>();
-
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(lines)?;
 
     let mut nodes: HashMap<char, Vec<MatrixIndex>> = HashMap::new();
 
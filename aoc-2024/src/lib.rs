@@ -35,3 +35,32 @@ pub mod day6;
 pub mod day7;
 pub mod day8;
 pub mod day9;
+
+aoc_common::register_year! {
+    year: 2024,
+    1 => day1::get_distance, "Day 1: Historian Hysteria";
+    2 => day2::count_safe_systems, "Day 2: Red-Nosed Reports";
+    3 => day3::parse_corrupted_data, "Day 3: Mull It Over";
+    4 => day4::get_answer, "Day 4: Ceres Search";
+    5 => day5::order_updates, "Day 5: Print Queue";
+    6 => day6::get_answer, "Day 6: DISPLAY NAME";
+    7 => day7::get_answer, "Day 7: DISPLAY NAME";
+    8 => day8::get_answer, "Day 8: DISPLAY NAME";
+    9 => day9::get_answer, "Day 9: DISPLAY NAME";
+    10 => day10::get_answer, "Day 10: DISPLAY NAME";
+    11 => day11::get_answer, "Day 11: DISPLAY NAME";
+    12 => day12::get_answer, "Day 12: DISPLAY NAME";
+    13 => day(day13::ClawContraption), "Day 13: DISPLAY NAME";
+    14 => day14::get_answer, "Day 14: DISPLAY NAME";
+    15 => day15::get_answer, "Day 15: DISPLAY NAME";
+    16 => day16::get_answer, "Day 16: DISPLAY NAME";
+    17 => day17::get_answer, "Day 17: DISPLAY NAME";
+    18 => day18::get_answer, "Day 18: DISPLAY NAME";
+    19 => day19::get_answer, "Day 19: DISPLAY NAME";
+    20 => day20::get_answer, "Day 20: DISPLAY NAME";
+    21 => day21::get_answer, "Day 21: DISPLAY NAME";
+    22 => text(day22::get_answer), "Day 22: DISPLAY NAME";
+    23 => day23::get_answer, "Day 23: DISPLAY NAME";
+    24 => day(day24::CrossedWires), "Day 24: DISPLAY NAME";
+    25 => day25::get_answer, "Day 25: DISPLAY NAME";
+}
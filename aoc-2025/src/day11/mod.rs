@@ -1,9 +1,15 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 
-use aoc_common::util;
+use aoc_common::util::{self, Interner};
+
+const YOU: usize = 0;
+const OUT: usize = 1;
+const SVR: usize = 2;
+const FFT: usize = 3;
+const DAC: usize = 4;
 
 struct DeviceMap {
-    name_to_id: HashMap<String, usize>,
+    names: Interner,
     outputs: Vec<Vec<usize>>,
     topo_order: Vec<usize>,
 }
@@ -11,22 +17,16 @@ struct DeviceMap {
 impl DeviceMap {
     fn new() -> Self {
         Self {
-            name_to_id: HashMap::new(),
+            names: Interner::with_names(["you", "out", "svr", "fft", "dac"]),
             outputs: vec![],
             topo_order: vec![],
         }
     }
 
     fn get_or_insert(&mut self, name: &str) -> usize {
-        if let Some(id) = self.name_to_id.get(name) {
-            return *id;
-        }
-
-        self.outputs.push(vec![]);
-        self.name_to_id
-            .insert(name.to_string(), self.outputs.len() - 1);
-
-        self.outputs.len() - 1
+        let id = self.names.intern(name);
+        self.outputs.resize_with(self.names.len(), Vec::new);
+        id
     }
 
     fn add_output(&mut self, from: &str, to: &str) {
@@ -63,10 +63,7 @@ impl DeviceMap {
         }
     }
 
-    fn count_paths(&self, from: &str, to: &str) -> usize {
-        let from = *self.name_to_id.get(from).unwrap();
-        let to = *self.name_to_id.get(to).unwrap();
-
+    fn count_paths(&self, from: usize, to: usize) -> usize {
         let mut path_count = vec![0; self.outputs.len()];
 
         path_count[from] = 1;
@@ -104,17 +101,15 @@ pub fn find_faulty_paths(
     }
     device_map.build_topo_order();
 
-    let path_count = device_map.count_paths("you", "out");
+    let path_count = device_map.count_paths(YOU, OUT);
 
-    let fft_to_dac_count = device_map.count_paths("fft", "dac");
+    let fft_to_dac_count = device_map.count_paths(FFT, DAC);
     let full_path_count = if fft_to_dac_count != 0 {
-        device_map.count_paths("svr", "fft")
-            * fft_to_dac_count
-            * device_map.count_paths("dac", "out")
+        device_map.count_paths(SVR, FFT) * fft_to_dac_count * device_map.count_paths(DAC, OUT)
     } else {
-        device_map.count_paths("svr", "dac")
-            * device_map.count_paths("dac", "fft")
-            * device_map.count_paths("fft", "out")
+        device_map.count_paths(SVR, DAC)
+            * device_map.count_paths(DAC, FFT)
+            * device_map.count_paths(FFT, OUT)
     };
 
     Ok((path_count, full_path_count))
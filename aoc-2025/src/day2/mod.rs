@@ -38,8 +38,7 @@ pub fn find_invalid_numbers(
 
     let line = lines.next().ok_or("There should be exactly one line")?;
     for range in line.split(',') {
-        let (from, to) = range.split_once('-').ok_or("Invalid range format")?;
-        let (from, to) = (from.parse::<usize>()?, to.parse::<usize>()?);
+        let (from, to) = aoc_common::scan!(range, "{}-{}" => (usize, usize))?;
 
         invalid_sum_halves += (from..=to)
             .filter(|num| is_invalid_halves(*num))
@@ -1,4 +1,4 @@
-use aoc_common::util::{self, MatrixIndex, VecMatrix};
+use aoc_common::util::{self, Direction8, MatrixIndex, VecMatrix};
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
 enum Tile {
@@ -18,50 +18,6 @@ impl TryFrom<char> for Tile {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-enum Direction {
-    Up,
-    UpRight,
-    Right,
-    DownRight,
-    Down,
-    DownLeft,
-    Left,
-    UpLeft,
-}
-
-impl Direction {
-    const fn all() -> [Self; 8] {
-        [
-            Self::Up,
-            Self::UpRight,
-            Self::Right,
-            Self::DownRight,
-            Self::Down,
-            Self::DownLeft,
-            Self::Left,
-            Self::UpLeft,
-        ]
-    }
-}
-
-fn next_idx<T>(
-    matrix: &VecMatrix<T>,
-    idx: MatrixIndex,
-    direction: Direction,
-) -> Option<MatrixIndex> {
-    match direction {
-        Direction::Up => matrix.next_up(idx),
-        Direction::UpRight => matrix.next_up(idx).and_then(|i| matrix.next_right(i)),
-        Direction::Right => matrix.next_right(idx),
-        Direction::DownRight => matrix.next_down(idx).and_then(|i| matrix.next_right(i)),
-        Direction::Down => matrix.next_down(idx),
-        Direction::DownLeft => matrix.next_down(idx).and_then(|i| matrix.next_left(i)),
-        Direction::Left => matrix.next_left(idx),
-        Direction::UpLeft => matrix.next_up(idx).and_then(|i| matrix.next_left(i)),
-    }
-}
-
 struct TileInfo {
     kind: Tile,
     papers: usize,
@@ -90,9 +46,8 @@ pub fn remove_paper(lines: impl Iterator<Item = String>) -> util::GenericResult<
     for row in 0..tilemap.height() {
         for col in 0..tilemap.width() {
             let idx = MatrixIndex { row, col };
-            tilemap[idx].papers = Direction::all()
-                .into_iter()
-                .filter_map(|d| next_idx(&tilemap, idx, d))
+            tilemap[idx].papers = tilemap
+                .neighbours8(idx)
                 .filter(|&next| tilemap[next].kind == Tile::Paper)
                 .count();
         }
@@ -115,8 +70,8 @@ pub fn remove_paper(lines: impl Iterator<Item = String>) -> util::GenericResult<
 
     while let Some(idx) = remove_stack.pop() {
         total_removed += 1;
-        for direction in Direction::all() {
-            if let Some(next) = next_idx(&tilemap, idx, direction) {
+        for direction in Direction8::ALL {
+            if let Some(next) = tilemap.step(idx, direction, 1) {
                 let tile = &mut tilemap[next];
                 tile.papers -= 1;
 
@@ -50,8 +50,8 @@ pub fn get_answer(mut lines: impl Iterator<Item = String>) -> util::GenericResul
     while let Some(line) = lines.next()
         && !line.is_empty()
     {
-        let (from, to) = line.split_once('-').ok_or("Range expected")?;
-        ranges.push(NumberRange::NonEmpty(from.parse()?, to.parse()?));
+        let (from, to) = aoc_common::scan!(&line, "{}-{}" => (i64, i64))?;
+        ranges.push(NumberRange::NonEmpty(from, to));
     }
 
     sort_and_merge(&mut ranges);
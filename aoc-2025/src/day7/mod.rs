@@ -10,30 +10,20 @@ enum Tile {
 }
 
 impl TryFrom<char> for Tile {
-    type Error = String;
+    type Error = util::CharParseError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             'S' => Ok(Self::Start),
             '.' => Ok(Self::Empty),
             '^' => Ok(Self::Splitter),
-            _ => Err("'S', '.' or '^' expected".to_string()),
+            ch => Err(util::CharParseError::new("'S', '.' or '^'", ch)),
         }
     }
 }
 
 pub fn count_beams(lines: impl Iterator<Item = String>) -> util::GenericResult<(usize, usize)> {
-    let tilemap = {
-        let mut lines = lines.peekable();
-
-        let width = lines.by_ref().peek().map_or(0, String::len);
-        let data = lines
-            .flat_map(String::into_bytes)
-            .map(|b| Tile::try_from(b as char))
-            .collect::<Result<Vec<_>, _>>()?;
-
-        VecMatrix::with_data(data, width)
-    };
+    let tilemap: VecMatrix<Tile> = VecMatrix::parse_lines(lines)?;
 
     let start = tilemap
         .iter_enumerate()
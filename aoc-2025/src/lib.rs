@@ -10,3 +10,19 @@ pub mod day6;
 pub mod day7;
 pub mod day8;
 pub mod day9;
+
+aoc_common::register_year! {
+    year: 2025,
+    1 => day1::crack_the_safe, "Day 1: Secret Entrance";
+    2 => day2::find_invalid_numbers, "Day 2: Gift Shop";
+    3 => day3::get_total_joltage, "Day 3: Lobby";
+    4 => day4::remove_paper, "Day 4: Printing Department";
+    5 => day5::get_answer, "Day 5: DISPLAY NAME";
+    6 => day6::do_maths, "Day 6: Trash Compactor";
+    7 => day7::count_beams, "Day 7: Laboratories";
+    8 => day8::connect_boxes, "Day 8: Playground";
+    9 => day9::find_largest_rectangle, "Day 9: Movie Theater";
+    10 => day10::count_button_presses, "Day 10: Factory";
+    11 => day11::find_faulty_paths, "Day 11: Reactor";
+    12 => day12::fit_presents, "Day 12: Christmas Tree Farm";
+}
@@ -0,0 +1,13 @@
+use std::fmt::Display;
+
+use crate::util;
+
+pub trait Day {
+    type Input;
+
+    fn parse(lines: impl Iterator<Item = String>) -> util::GenericResult<Self::Input>;
+
+    fn part1(input: &Self::Input) -> util::GenericResult<impl Display>;
+
+    fn part2(input: &Self::Input) -> util::GenericResult<impl Display>;
+}
@@ -0,0 +1,112 @@
+// Generates a `#[test]` for every puzzle example attached to a day. Examples are either inline
+// strings or files in the crate's `examples/` directory, `_` skips checking the part:
+//
+// aoc_common::examples! {
+//     day: Aplenty;
+//     example: "..." => 19114, 167409079868000;
+//     larger: file("day19-2.txt") => _, 42;
+// }
+#[macro_export]
+macro_rules! examples {
+    (day: $solution:ty; $($examples:tt)*) => {
+        #[cfg(test)]
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+
+            fn check(name: &str, input: &str, expected: [Option<String>; 2]) {
+                $crate::Solution::new(name)
+                    .day::<$solution>()
+                    .assert_example(input, expected);
+            }
+
+            $crate::examples!(@tests $($examples)*);
+        }
+    };
+
+    (solution: $func:expr; $($examples:tt)*) => {
+        #[cfg(test)]
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+
+            fn check(name: &str, input: &str, expected: [Option<String>; 2]) {
+                $crate::Solution::new(name)
+                    .solution(|lines| $func(lines))
+                    .assert_example(input, expected);
+            }
+
+            $crate::examples!(@tests $($examples)*);
+        }
+    };
+
+    (text: $func:expr; $($examples:tt)*) => {
+        #[cfg(test)]
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+
+            fn check(name: &str, input: &str, expected: [Option<String>; 2]) {
+                $crate::Solution::new(name)
+                    .text_solution(|input| $func(input))
+                    .assert_example(input, expected);
+            }
+
+            $crate::examples!(@tests $($examples)*);
+        }
+    };
+
+    (@tests) => {};
+
+    (@tests $name:ident: file($path:literal) => $part1:tt, $part2:tt; $($rest:tt)*) => {
+        #[test]
+        fn $name() {
+            check(
+                stringify!($name),
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/", $path)),
+                [$crate::examples!(@expected $part1), $crate::examples!(@expected $part2)],
+            );
+        }
+
+        $crate::examples!(@tests $($rest)*);
+    };
+
+    (@tests $name:ident: $input:literal => $part1:tt, $part2:tt; $($rest:tt)*) => {
+        #[test]
+        fn $name() {
+            check(
+                stringify!($name),
+                $input,
+                [$crate::examples!(@expected $part1), $crate::examples!(@expected $part2)],
+            );
+        }
+
+        $crate::examples!(@tests $($rest)*);
+    };
+
+    (@expected _) => {
+        None
+    };
+
+    (@expected $answer:tt) => {
+        Some($crate::ExampleAnswer::into_answer($answer))
+    };
+}
+
+// Answers are written as plain literals, a single integer impl keeps large numbers from being
+// inferred as `i32`
+pub trait ExampleAnswer {
+    fn into_answer(self) -> String;
+}
+
+impl ExampleAnswer for &str {
+    fn into_answer(self) -> String {
+        self.to_owned()
+    }
+}
+
+impl ExampleAnswer for i128 {
+    fn into_answer(self) -> String {
+        self.to_string()
+    }
+}
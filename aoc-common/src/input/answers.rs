@@ -0,0 +1,297 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+use crate::util::{
+    self,
+    iter::ResultIteratorExtended,
+    lexer::{self, Lexer},
+};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AnswersError {
+    ParsingFailed { line: usize, error: lexer::Error },
+    KeyOutsideOfSection { line: usize },
+    UnknownKey { line: usize, key: String },
+    UnknownEscape { line: usize, escape: char },
+    UnterminatedString { line: usize },
+}
+
+impl std::fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ParsingFailed { line, error } => write!(f, "line {line}: {error}"),
+            Self::KeyOutsideOfSection { line } => {
+                write!(f, "line {line}: answers must be inside a [dayN] section")
+            }
+            Self::UnknownKey { line, key } => {
+                write!(
+                    f,
+                    "line {line}: unknown key `{key}`, expected `part1` or `part2`"
+                )
+            }
+            Self::UnknownEscape { line, escape } => {
+                write!(f, "line {line}: unknown escape sequence `\\{escape}`")
+            }
+            Self::UnterminatedString { line } => {
+                write!(f, "line {line}: the string starting here is never closed")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ParsingFailed { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+enum Value<'a> {
+    Bare(&'a str),
+    Basic(&'a str),
+    MultiLine { start: &'a str, closed: bool },
+}
+
+fn parse_comment(lexer: &mut Lexer<'_>) -> lexer::Result<()> {
+    lexer.whitespace()?;
+    if lexer.literal("#").is_err() {
+        lexer.end()?;
+    }
+    Ok(())
+}
+
+fn parse_section(s: &str) -> lexer::Result<u32> {
+    let mut lexer = Lexer::of(s);
+    let mut day = 0;
+
+    lexer
+        .chain()
+        .literal("[day")?
+        .unsigned_number(&mut day)?
+        .literal("]")?;
+    parse_comment(&mut lexer)?;
+
+    Ok(day)
+}
+
+fn parse_key_value(s: &str) -> lexer::Result<(&str, Value<'_>)> {
+    let mut lexer = Lexer::of(s);
+
+    let key = lexer.take_while(|ch| ch.is_ascii_alphanumeric() || ch == '_')?;
+    lexer.chain().whitespace()?.literal("=")?.whitespace()?;
+
+    let value = if lexer.literal("\"\"\"").is_ok() {
+        let rest = lexer.take_rest()?;
+        match rest.split_once("\"\"\"") {
+            Some((start, trailer)) => {
+                parse_comment(&mut Lexer::of(trailer))?;
+                Value::MultiLine { start, closed: true }
+            }
+            None => Value::MultiLine { start: rest, closed: false },
+        }
+    } else if lexer.literal("\"").is_ok() {
+        let mut escaped = false;
+        let value = lexer.take_while(|ch| {
+            let end = ch == '"' && !escaped;
+            escaped = ch == '\\' && !escaped;
+            !end
+        })?;
+        lexer.literal("\"")?;
+        parse_comment(&mut lexer)?;
+        Value::Basic(value)
+    } else {
+        let value = lexer.take_while(|ch| ch.is_ascii_digit() || ch == '-')?;
+        parse_comment(&mut lexer)?;
+        Value::Bare(value)
+    };
+
+    Ok((key, value))
+}
+
+fn unescape(s: &str, line: usize) -> Result<String, AnswersError> {
+    let mut res = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            res.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('\\') => res.push('\\'),
+            Some('"') => res.push('"'),
+            Some('n') => res.push('\n'),
+            Some('t') => res.push('\t'),
+            Some(escape) => return Err(AnswersError::UnknownEscape { line, escape }),
+            None => return Err(AnswersError::UnterminatedString { line }),
+        }
+    }
+
+    Ok(res)
+}
+
+// A small subset of TOML: `[dayN]` sections with `part1`/`part2` keys, whose values are
+// either bare numbers, "basic strings" or """multi-line strings""".
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: HashMap<u32, Expected>,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> util::GenericResult<Self> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut lines = BufReader::new(file).lines().end_on_error();
+        let answers = Self::parse(&mut lines)?;
+        lines.into_err()?;
+
+        Ok(answers)
+    }
+
+    pub fn parse(lines: impl Iterator<Item = String>) -> Result<Self, AnswersError> {
+        let mut answers = Self::default();
+        let mut current = None;
+        let mut lines = lines.enumerate().map(|(idx, line)| (idx + 1, line));
+
+        while let Some((line, s)) = lines.next() {
+            let parsing_failed = |error| AnswersError::ParsingFailed { line, error };
+
+            let trimmed = s.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if trimmed.starts_with('[') {
+                let day = parse_section(trimmed).map_err(parsing_failed)?;
+                answers.days.entry(day).or_default();
+                current = Some(day);
+                continue;
+            }
+
+            let day = current.ok_or(AnswersError::KeyOutsideOfSection { line })?;
+            let (key, value) = parse_key_value(trimmed).map_err(parsing_failed)?;
+
+            let value = match value {
+                Value::Bare(value) => value.to_owned(),
+                Value::Basic(value) => unescape(value, line)?,
+                Value::MultiLine { start, closed: true } => unescape(start, line)?,
+                Value::MultiLine { start, closed: false } => {
+                    // Newline right after the opening quotes is not a part of the string
+                    let mut raw = start.to_owned();
+                    let mut skip_newline = start.is_empty();
+                    loop {
+                        let (_, next) = lines
+                            .next()
+                            .ok_or(AnswersError::UnterminatedString { line })?;
+
+                        if !skip_newline {
+                            raw.push('\n');
+                        }
+                        skip_newline = false;
+
+                        if let Some((end, trailer)) = next.split_once("\"\"\"") {
+                            parse_comment(&mut Lexer::of(trailer)).map_err(parsing_failed)?;
+                            raw.push_str(end);
+                            break;
+                        }
+                        raw.push_str(&next);
+                    }
+                    unescape(&raw, line)?
+                }
+            };
+
+            let expected = answers.days.entry(day).or_default();
+            match key {
+                "part1" => expected.part1 = Some(value),
+                "part2" => expected.part2 = Some(value),
+                _ => return Err(AnswersError::UnknownKey { line, key: key.to_owned() }),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    #[must_use]
+    pub fn get(&self, day: u32) -> Expected {
+        self.days.get(&day).cloned().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, AnswersError, Expected};
+
+    fn parse(s: &str) -> Result<Answers, AnswersError> {
+        Answers::parse(s.lines().map(ToOwned::to_owned))
+    }
+
+    #[test]
+    fn values() {
+        let answers = parse(
+            "# comment\n[day1]\npart1 = 42\npart2 = \"a \\\"b\\\"\" # comment\n\n[day3]\npart2 = \"\"\"\n#.#\n.#.\n\"\"\"",
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.get(1),
+            Expected {
+                part1: Some("42".into()),
+                part2: Some("a \"b\"".into())
+            }
+        );
+        assert_eq!(answers.get(2), Expected::default());
+        assert_eq!(
+            answers.get(3),
+            Expected {
+                part1: None,
+                part2: Some("#.#\n.#.\n".into())
+            }
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse("part1 = 1").unwrap_err(),
+            AnswersError::KeyOutsideOfSection { line: 1 }
+        );
+        assert_eq!(
+            parse("[day1]\npart3 = 1").unwrap_err(),
+            AnswersError::UnknownKey { line: 2, key: "part3".into() }
+        );
+        assert_eq!(
+            parse("[day1]\npart1 = \"\"\"\nabc").unwrap_err(),
+            AnswersError::UnterminatedString { line: 2 }
+        );
+
+        assert_eq!(
+            parse("[day1]\npart3 = 1").unwrap_err().to_string(),
+            "line 2: unknown key `part3`, expected `part1` or `part2`"
+        );
+        assert_eq!(
+            parse("[day1]\npart1 = \"a\\qb\"").unwrap_err().to_string(),
+            "line 2: unknown escape sequence `\\q`"
+        );
+        assert_eq!(
+            parse("[day1\n").unwrap_err().to_string(),
+            "line 1: expected literal `]`, found end of line"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::{self, Display, Write};
+
+// Just enough JSON to report a run, keys keep their insertion order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Number(u128),
+    String(String),
+    Object(Vec<(&'static str, Self)>),
+}
+
+impl From<Option<Self>> for Value {
+    fn from(value: Option<Self>) -> Self {
+        value.unwrap_or(Self::Null)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for ch in s.chars() {
+        match ch {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            ch if ch.is_control() => write!(f, "\\u{:04x}", ch as u32)?,
+            ch => f.write_char(ch)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Number(num) => write!(f, "{num}"),
+            Self::String(s) => write_string(f, s),
+            Self::Object(fields) => {
+                f.write_char('{')?;
+                for (idx, (key, value)) in fields.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    #[test]
+    fn display() {
+        let value = Value::Object(vec![
+            ("title", "Day 1: \"Quoted\"\n".into()),
+            ("day", Value::Number(1)),
+            ("error", Value::Null),
+            ("nested", Value::Object(vec![("tab", "\t\u{1}".into())])),
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"{"title":"Day 1: \"Quoted\"\n","day":1,"error":null,"nested":{"tab":"\t\u0001"}}"#
+        );
+    }
+}
@@ -1,63 +1,584 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
+use std::{error::Error, io::Read, panic::AssertUnwindSafe, path::Path};
+
+use crate::{
+    Day,
+    util::{self, InputError, lexer},
 };
 
-use crate::util::{self, iter::ResultIteratorExtended};
+mod answers;
+pub use answers::{Answers, AnswersError, Expected};
 
-pub struct Solution<T1, T2, F>
-where
-    T1: std::fmt::Display,
-    T2: std::fmt::Display,
-    F: Fn(&mut dyn Iterator<Item = String>) -> util::GenericResult<(T1, T2)>,
-{
+mod json;
+
+mod timings;
+pub use timings::Timings;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Verdict {
+    fn of(answer: &str, expected: Option<&str>) -> Self {
+        // Trailing whitespace is not significant, e.g. in rendered multi-line answers
+        fn normalize(s: &str) -> impl Iterator<Item = &str> {
+            s.trim_end().lines().map(str::trim_end)
+        }
+
+        match expected {
+            None => Self::Missing,
+            Some(expected) if normalize(answer).eq(normalize(expected)) => Self::Pass,
+            Some(_) => Self::Fail,
+        }
+    }
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Solved,
+    Mismatch,
+    Error,
+}
+
+impl Outcome {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Solved => "solved",
+            Self::Mismatch => "mismatch",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Human,
+    // A single JSON object per run, on one line. Days keep stdout free for it and print any debug
+    // output to stderr
+    Json,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RunOptions {
+    pub expected: Option<Expected>,
+    pub iterations: Option<usize>,
+    pub part: Option<usize>,
+    pub format: Format,
+}
+
+#[derive(Debug)]
+pub struct Panicked(String);
+
+impl std::fmt::Display for Panicked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "panicked: {}", self.0)
+    }
+}
+
+impl std::error::Error for Panicked {}
+
+fn catch_panic<T>(func: impl FnOnce() -> util::GenericResult<T>) -> util::GenericResult<T> {
+    std::panic::catch_unwind(AssertUnwindSafe(func)).unwrap_or_else(|payload| {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_owned())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(Panicked(msg).into())
+    })
+}
+
+// Points a parsing error at the line that was being parsed when it happened, unless the error
+// knows better. Lexer errors anywhere in the source chain also give the column
+fn locate_error(error: Box<dyn Error>, input: &str, consumed: usize) -> Box<dyn Error> {
+    let lexer_error = std::iter::successors(Some(error.as_ref()), |&e| e.source())
+        .find_map(|e| e.downcast_ref::<lexer::Error>())
+        .map(|e| (e.input().to_owned(), e.position()));
+
+    let error = match error.downcast::<InputError>() {
+        Ok(error) => *error,
+        Err(error) if lexer_error.is_some() => InputError::new(error),
+        Err(error) => return error,
+    };
+
+    let line = error.line().unwrap_or(consumed);
+    let Some(text) = line.checked_sub(1).and_then(|idx| input.lines().nth(idx)) else {
+        return Box::new(error);
+    };
+
+    let column = error.column().or_else(|| {
+        // The lexer might have been given only a part of the line
+        let (input, position) = lexer_error?;
+        Some(text.find(&input)? + position)
+    });
+
+    let error = error.at_line(line).with_text(text);
+    Box::new(match column {
+        Some(column) => error.at_column(column),
+        None => error,
+    })
+}
+
+type LinesParser<I> = Box<dyn Fn(&mut dyn Iterator<Item = String>) -> util::GenericResult<I>>;
+type TextParser<I> = Box<dyn Fn(&str) -> util::GenericResult<I>>;
+
+// Line parsers get an owned `String` per line, text parsers borrow the whole input at once and
+// avoid those allocations
+enum Parser<I> {
+    Lines(LinesParser<I>),
+    Text(TextParser<I>),
+}
+
+type Part<I> = Box<dyn Fn(&I) -> util::GenericResult<String>>;
+type PartAnswers = [Option<util::GenericResult<String>>; 2];
+
+const PART_PHASES: [&str; 2] = ["part 1", "part 2"];
+
+pub struct Solution<I> {
     title: String,
-    processor: Option<F>,
+    puzzle: Option<(u32, u32)>,
+    parser: Option<Parser<I>>,
+    parts: [Option<Part<I>>; 2],
+    options: RunOptions,
 }
 
-impl<T1, T2, F> Solution<T1, T2, F>
-where
-    T1: std::fmt::Display,
-    T2: std::fmt::Display,
-    F: Fn(&mut dyn Iterator<Item = String>) -> util::GenericResult<(T1, T2)>,
-{
+impl<I> Solution<I> {
     pub fn new(title: impl Into<String>) -> Self {
-        Self { title: title.into(), processor: None }
+        Self {
+            title: title.into(),
+            puzzle: None,
+            parser: None,
+            parts: [None, None],
+            options: RunOptions::default(),
+        }
     }
 
+    // Year and day of the puzzle, only reported in the JSON output
     #[must_use]
-    pub fn solution(mut self, func: F) -> Self {
-        self.processor = Some(func);
+    pub const fn puzzle(mut self, year: u32, day: u32) -> Self {
+        self.puzzle = Some((year, day));
         self
     }
 
-    fn do_run(self, input: impl AsRef<std::path::Path>) -> util::GenericResult<()> {
-        println!();
-        println!("{}", self.title);
-        println!();
+    #[must_use]
+    pub fn parser<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&mut dyn Iterator<Item = String>) -> util::GenericResult<I> + 'static,
+    {
+        self.parser = Some(Parser::Lines(Box::new(func)));
+        self
+    }
 
-        let reader = BufReader::new(File::open(input)?);
-        let mut iter = reader.lines().end_on_error();
+    #[must_use]
+    pub fn text_parser<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&str) -> util::GenericResult<I> + 'static,
+    {
+        self.parser = Some(Parser::Text(Box::new(func)));
+        self
+    }
 
-        let (ans1, ans2) = self.processor.ok_or("Processor is None")?(&mut iter)?;
-        iter.into_err()?;
+    fn part<T, F>(mut self, num: usize, func: F) -> Self
+    where
+        T: std::fmt::Display,
+        F: Fn(&I) -> util::GenericResult<T> + 'static,
+    {
+        self.parts[num - 1] = Some(Box::new(move |input| {
+            func(input).map(|ans| ans.to_string())
+        }));
+        self
+    }
 
-        println!("--- Part 1 ---");
-        println!();
-        println!("{ans1}");
+    #[must_use]
+    pub fn part1<T, F>(self, func: F) -> Self
+    where
+        T: std::fmt::Display,
+        F: Fn(&I) -> util::GenericResult<T> + 'static,
+    {
+        self.part(1, func)
+    }
+
+    #[must_use]
+    pub fn part2<T, F>(self, func: F) -> Self
+    where
+        T: std::fmt::Display,
+        F: Fn(&I) -> util::GenericResult<T> + 'static,
+    {
+        self.part(2, func)
+    }
+
+    #[must_use]
+    pub fn day<D: Day<Input = I>>(self) -> Self {
+        self.parser(|lines| D::parse(lines))
+            .part1(|input| D::part1(input).map(|ans| ans.to_string()))
+            .part2(|input| D::part2(input).map(|ans| ans.to_string()))
+    }
+
+    #[must_use]
+    pub fn expected(mut self, expected: Expected) -> Self {
+        self.options.expected = Some(expected);
+        self
+    }
+
+    #[must_use]
+    pub fn bench(mut self, iterations: usize) -> Self {
+        self.options.iterations = Some(iterations.max(1));
+        self
+    }
+
+    #[must_use]
+    pub const fn only_part(mut self, num: usize) -> Self {
+        self.options.part = Some(num);
+        self
+    }
+
+    #[must_use]
+    pub fn with_options(mut self, options: RunOptions) -> Self {
+        self.options = RunOptions {
+            iterations: options.iterations.map(|iterations| iterations.max(1)),
+            ..options
+        };
+        self
+    }
+
+    fn is_selected(&self, num: usize) -> bool {
+        self.options.part.is_none_or(|part| part == num)
+    }
+
+    // `None` when not running in verification mode, otherwise the verdict and the expected answer
+    fn check(&self, num: usize, answer: &str) -> Option<(Verdict, Option<&str>)> {
+        let expected = self.options.expected.as_ref()?;
+        let expected = if num == 1 { &expected.part1 } else { &expected.part2 };
+
+        Some((
+            Verdict::of(answer, expected.as_deref()),
+            expected.as_deref(),
+        ))
+    }
+
+    fn outcome(&self, answers: &PartAnswers) -> Outcome {
+        let mut outcome = Outcome::Solved;
+        for (idx, answer) in answers.iter().enumerate() {
+            match answer {
+                Some(Err(_)) => outcome = Outcome::Error,
+                Some(Ok(answer)) if outcome == Outcome::Solved => {
+                    if let Some((Verdict::Fail, _)) = self.check(idx + 1, answer) {
+                        outcome = Outcome::Mismatch;
+                    }
+                }
+                _ => {}
+            }
+        }
+        outcome
+    }
+
+    fn print_part(&self, num: usize, answer: &util::GenericResult<String>) {
+        println!("--- Part {num} ---");
         println!();
-        println!("--- Part 2 ---");
+
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(e) => {
+                println!("FAILED: {e}");
+                println!();
+                return;
+            }
+        };
+
+        println!("{answer}");
         println!();
-        println!("{ans2}");
+
+        match self.check(num, answer) {
+            Some((Verdict::Fail, Some(expected))) => {
+                println!("FAIL, expected:");
+                println!();
+                println!("{expected}");
+            }
+            Some((Verdict::Pass, _)) => println!("PASS"),
+            Some(_) => println!("MISSING"),
+            None => return,
+        }
         println!();
+    }
+
+    fn print_human(&self, result: &util::GenericResult<PartAnswers>, timings: &Timings) {
+        let answers = match result {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("FAILED: {e}");
+                return;
+            }
+        };
+
+        for (idx, answer) in answers.iter().enumerate() {
+            if let Some(answer) = answer {
+                self.print_part(idx + 1, answer);
+            }
+        }
+
+        if self.options.iterations.is_some() {
+            timings.print();
+        }
+    }
+
+    fn part_json(&self, num: usize, answer: Option<&util::GenericResult<String>>) -> json::Value {
+        let Some(answer) = answer else {
+            return json::Value::Null;
+        };
+
+        let (answer, error) = match answer {
+            Ok(answer) => (Some(answer.as_str()), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        let (verdict, expected) = answer
+            .and_then(|answer| self.check(num, answer))
+            .map_or((None, None), |(verdict, expected)| {
+                (Some(verdict), expected)
+            });
+
+        json::Value::Object(vec![
+            ("answer", answer.map(Into::into).into()),
+            ("verdict", verdict.map(|v| v.as_str().into()).into()),
+            ("expected", expected.map(Into::into).into()),
+            ("error", error.map(json::Value::String).into()),
+        ])
+    }
+
+    fn to_json(
+        &self,
+        result: &util::GenericResult<PartAnswers>,
+        timings: &Timings,
+        outcome: Outcome,
+    ) -> json::Value {
+        let no_answers = [None, None];
+        let answers = result.as_ref().unwrap_or(&no_answers);
+        let (year, day) = self.puzzle.unzip();
+
+        let phases = timings
+            .summary()
+            .map(|(name, min, median, max)| {
+                let phase = [("min_ns", min), ("median_ns", median), ("max_ns", max)]
+                    .map(|(key, time)| (key, json::Value::Number(time.as_nanos())));
+                (name, json::Value::Object(phase.into()))
+            })
+            .collect();
+
+        json::Value::Object(vec![
+            ("title", self.title.as_str().into()),
+            (
+                "year",
+                year.map(|year| json::Value::Number(year.into())).into(),
+            ),
+            ("day", day.map(|day| json::Value::Number(day.into())).into()),
+            ("outcome", outcome.as_str().into()),
+            ("part1", self.part_json(1, answers[0].as_ref())),
+            ("part2", self.part_json(2, answers[1].as_ref())),
+            (
+                "iterations",
+                json::Value::Number(timings.iterations() as u128),
+            ),
+            ("timings", json::Value::Object(phases)),
+            (
+                "error",
+                result
+                    .as_ref()
+                    .err()
+                    .map(|e| e.to_string().as_str().into())
+                    .into(),
+            ),
+        ])
+    }
+
+    fn read_input(mut reader: impl Read) -> util::GenericResult<String> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(input)
+    }
+
+    fn parse(parser: &Parser<I>, input: &str) -> util::GenericResult<I> {
+        match parser {
+            Parser::Lines(parser) => {
+                let mut consumed = 0;
+                let mut lines = input
+                    .lines()
+                    .inspect(|_| consumed += 1)
+                    .map(ToOwned::to_owned);
+                let parsed = parser(&mut lines);
+                parsed.map_err(|e| locate_error(e, input, consumed))
+            }
+            // Nothing tells which line was being read, only errors that know their line get the text
+            Parser::Text(parser) => parser(input).map_err(|e| locate_error(e, input, 0)),
+        }
+    }
+
+    fn solve(
+        &self,
+        input: &str,
+        selected: [bool; 2],
+        timings: &mut Timings,
+        answers: &mut PartAnswers,
+    ) -> util::GenericResult<()> {
+        let parser = self.parser.as_ref().ok_or("Parser is None")?;
+        let parsed = timings.measure("parse", || catch_panic(|| Self::parse(parser, input)))?;
+
+        for (idx, part) in self.parts.iter().enumerate() {
+            if !selected[idx] {
+                continue;
+            }
+
+            let Some(part) = part else {
+                answers[idx].get_or_insert_with(|| Err(format!("Part {} is None", idx + 1).into()));
+                continue;
+            };
+
+            let answer = timings.measure(PART_PHASES[idx], || catch_panic(|| part(&parsed)));
+            answers[idx].get_or_insert(answer);
+        }
 
         Ok(())
     }
 
-    pub fn run(self, input: impl AsRef<std::path::Path>) {
-        let result = self.do_run(input);
-        if let Err(e) = result {
-            eprintln!("{e:#?}");
+    // With `reread` the input is read on every benchmark iteration, otherwise only once and the
+    // later iterations reuse it without timing anything
+    fn do_run(
+        &self,
+        mut read_input: impl FnMut() -> util::GenericResult<String>,
+        reread: bool,
+        timings: &mut Timings,
+    ) -> util::GenericResult<PartAnswers> {
+        let selected = [self.is_selected(1), self.is_selected(2)];
+        let mut answers = [None, None];
+        let mut input = None;
+
+        for _ in 0..self.options.iterations.unwrap_or(1) {
+            let input = match input {
+                Some(ref input) if !reread => input,
+                _ => input.insert(timings.measure("read input", &mut read_input)?),
+            };
+            self.solve(input, selected, timings, &mut answers)?;
+        }
+
+        Ok(answers)
+    }
+
+    fn run_with(
+        self,
+        read_input: impl FnMut() -> util::GenericResult<String>,
+        reread: bool,
+    ) -> Outcome {
+        if self.options.format == Format::Human {
+            println!();
+            println!("{}", self.title);
+            println!();
+        }
+
+        let mut timings = Timings::default();
+        let result = self.do_run(read_input, reread, &mut timings);
+        let outcome = result
+            .as_ref()
+            .map_or(Outcome::Error, |answers| self.outcome(answers));
+
+        match self.options.format {
+            Format::Human => self.print_human(&result, &timings),
+            Format::Json => println!("{}", self.to_json(&result, &timings, outcome)),
+        }
+
+        outcome
+    }
+
+    // `-` reads the input from stdin
+    #[must_use]
+    pub fn run(self, input: impl AsRef<Path>) -> Outcome {
+        let input = input.as_ref();
+        if input == Path::new("-") {
+            return self.run_with_reader(std::io::stdin().lock());
         }
+
+        self.run_with(|| Ok(std::fs::read_to_string(input)?), true)
+    }
+
+    // A reader can only be consumed once, benchmark iterations reuse its input
+    #[must_use]
+    pub fn run_with_reader(self, reader: impl Read) -> Outcome {
+        let mut reader = Some(reader);
+        self.run_with(
+            move || Self::read_input(reader.take().ok_or("The input was already read")?),
+            false,
+        )
+    }
+
+    #[must_use]
+    pub fn run_str(self, input: &str) -> Outcome {
+        self.run_with_reader(input.as_bytes())
+    }
+
+    // Panics if any of the expected answers is not produced, for use in `#[test]` functions
+    pub fn assert_example(&self, input: &str, expected: [Option<String>; 2]) {
+        // Allow inline examples to start on a new line after the opening quote
+        let input = input.strip_prefix('\n').unwrap_or(input);
+
+        let mut answers = [None, None];
+        if let Err(e) = self.solve(
+            input,
+            [expected[0].is_some(), expected[1].is_some()],
+            &mut Timings::default(),
+            &mut answers,
+        ) {
+            panic!("{}: {e}", self.title);
+        }
+
+        for (idx, (answer, expected)) in answers.into_iter().zip(expected).enumerate() {
+            let (Some(answer), Some(expected)) = (answer, expected) else {
+                continue;
+            };
+
+            match answer {
+                Ok(answer) => assert!(
+                    Verdict::of(&answer, Some(&expected)) == Verdict::Pass,
+                    "{}: part {} answer is {answer}, expected {expected}",
+                    self.title,
+                    idx + 1,
+                ),
+                Err(e) => panic!("{}: part {} failed: {e}", self.title, idx + 1),
+            }
+        }
+    }
+}
+
+impl<T1, T2> Solution<(T1, T2)>
+where
+    T1: std::fmt::Display + 'static,
+    T2: std::fmt::Display + 'static,
+{
+    // Older solutions compute both parts at once, so both answers become the "parsed input"
+    #[must_use]
+    pub fn solution<F>(self, func: F) -> Self
+    where
+        F: Fn(&mut dyn Iterator<Item = String>) -> util::GenericResult<(T1, T2)> + 'static,
+    {
+        self.parser(func)
+            .part1(|(ans1, _)| Ok(ans1.to_string()))
+            .part2(|(_, ans2)| Ok(ans2.to_string()))
+    }
+
+    #[must_use]
+    pub fn text_solution<F>(self, func: F) -> Self
+    where
+        F: Fn(&str) -> util::GenericResult<(T1, T2)> + 'static,
+    {
+        self.text_parser(func)
+            .part1(|(ans1, _)| Ok(ans1.to_string()))
+            .part2(|(_, ans2)| Ok(ans2.to_string()))
     }
 }
@@ -0,0 +1,78 @@
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default)]
+pub struct Timings {
+    phases: Vec<(&'static str, Vec<Duration>)>,
+}
+
+impl Timings {
+    pub fn measure<T>(&mut self, phase: &'static str, func: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let ret = func();
+        self.record(phase, start.elapsed());
+        ret
+    }
+
+    pub fn record(&mut self, phase: &'static str, duration: Duration) {
+        match self.phases.iter_mut().find(|(name, _)| *name == phase) {
+            Some((_, samples)) => samples.push(duration),
+            None => self.phases.push((phase, vec![duration])),
+        }
+    }
+
+    // (phase, min, median, max) for every recorded phase, in order of first appearance
+    pub fn summary(&self) -> impl Iterator<Item = (&'static str, Duration, Duration, Duration)> {
+        self.phases.iter().map(|(name, samples)| {
+            let mut sorted = samples.clone();
+            sorted.sort_unstable();
+            (
+                *name,
+                sorted[0],
+                sorted[sorted.len() / 2],
+                sorted[sorted.len() - 1],
+            )
+        })
+    }
+
+    #[must_use]
+    pub fn iterations(&self) -> usize {
+        self.phases
+            .iter()
+            .map(|(_, samples)| samples.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn print(&self) {
+        let width = self
+            .phases
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+
+        if self.iterations() <= 1 {
+            println!("--- Timings ---");
+            println!();
+            for (name, time, _, _) in self.summary() {
+                println!("{name:<width$}  {:>12}", format!("{time:.3?}"));
+            }
+        } else {
+            println!("--- Timings ({} iterations) ---", self.iterations());
+            println!();
+            println!(
+                "{:<width$}  {:>12}  {:>12}  {:>12}",
+                "", "min", "median", "max"
+            );
+            for (name, min, median, max) in self.summary() {
+                println!(
+                    "{name:<width$}  {:>12}  {:>12}  {:>12}",
+                    format!("{min:.3?}"),
+                    format!("{median:.3?}"),
+                    format!("{max:.3?}"),
+                );
+            }
+        }
+        println!();
+    }
+}
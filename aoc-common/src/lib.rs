@@ -10,7 +10,19 @@
     clippy::cast_sign_loss
 )]
 
+mod day;
+pub use day::Day;
+
+mod examples;
+pub use examples::ExampleAnswer;
+
 mod input;
-pub use input::Solution;
+pub use input::{
+    Answers, AnswersError, Expected, Format, Outcome, Panicked, RunOptions, Solution, Timings,
+    Verdict,
+};
+
+mod registry;
+pub use registry::{Puzzle, Runner, Year};
 
 pub mod util;
@@ -0,0 +1,127 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Answers, Outcome, RunOptions, util};
+
+pub type Runner = fn(&Path, RunOptions) -> Outcome;
+
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub day: u32,
+    pub title: &'static str,
+    pub runner: Runner,
+}
+
+impl Puzzle {
+    pub fn run(&self, input: impl AsRef<Path>, options: RunOptions) -> Outcome {
+        (self.runner)(input.as_ref(), options)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Year {
+    pub year: u32,
+    pub root: &'static str,
+    pub puzzles: &'static [Puzzle],
+}
+
+impl Year {
+    #[must_use]
+    pub fn puzzle(&self, day: u32) -> Option<&Puzzle> {
+        self.puzzles.iter().find(|p| p.day == day)
+    }
+
+    #[must_use]
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        [self.root, "inputs", &format!("day{day}.txt")]
+            .iter()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn answers_path(&self) -> PathBuf {
+        [self.root, "answers.toml"].iter().collect()
+    }
+
+    pub fn answers(&self) -> util::GenericResult<Answers> {
+        Answers::load(self.answers_path())
+    }
+}
+
+// Defines the `YEAR` of a year crate. New days implement `Day` and are registered with `day(..)`,
+// which is also what `aoc new` generates. `text(..)` takes a function of the whole input, and a bare
+// function of the lines is the form of days written before `Day`:
+//
+// aoc_common::register_year! {
+//     year: 2024,
+//     1 => day1::get_distance, "Day 1: Historian Hysteria";
+//     13 => day(day13::ClawContraption), "Day 13: Claw Contraption";
+//     22 => text(day22::get_answer), "Day 22: Monkey Market";
+// }
+#[macro_export]
+macro_rules! register_year {
+    (year: $year:literal, $($puzzles:tt)*) => {
+        pub const YEAR: $crate::Year = $crate::Year {
+            year: $year,
+            root: env!("CARGO_MANIFEST_DIR"),
+            puzzles: &$crate::register_year!(@puzzles $year [] $($puzzles)*),
+        };
+    };
+
+    (@puzzles $year:literal [$($done:expr,)*]) => {
+        [$($done,)*]
+    };
+
+    (
+        @puzzles $year:literal [$($done:expr,)*]
+        $day:literal => day($solution:ty), $title:literal;
+        $($rest:tt)*
+    ) => {
+        $crate::register_year!(@puzzles $year [$($done,)* $crate::Puzzle {
+            day: $day,
+            title: $title,
+            runner: |input, options| {
+                $crate::Solution::new($title)
+                    .puzzle($year, $day)
+                    .day::<$solution>()
+                    .with_options(options)
+                    .run(input)
+            },
+        },] $($rest)*)
+    };
+
+    (
+        @puzzles $year:literal [$($done:expr,)*]
+        $day:literal => text($func:expr), $title:literal;
+        $($rest:tt)*
+    ) => {
+        $crate::register_year!(@puzzles $year [$($done,)* $crate::Puzzle {
+            day: $day,
+            title: $title,
+            runner: |input, options| {
+                $crate::Solution::new($title)
+                    .puzzle($year, $day)
+                    .text_solution(|input| $func(input))
+                    .with_options(options)
+                    .run(input)
+            },
+        },] $($rest)*)
+    };
+
+    (
+        @puzzles $year:literal [$($done:expr,)*]
+        $day:literal => $func:expr, $title:literal;
+        $($rest:tt)*
+    ) => {
+        $crate::register_year!(@puzzles $year [$($done,)* $crate::Puzzle {
+            day: $day,
+            title: $title,
+            runner: |input, options| {
+                $crate::Solution::new($title)
+                    .puzzle($year, $day)
+                    .solution(|lines| $func(lines))
+                    .with_options(options)
+                    .run(input)
+            },
+        },] $($rest)*)
+    };
+}
@@ -0,0 +1,83 @@
+use std::{error::Error, fmt::Display};
+
+// The input breaks an assumption that holds for real puzzle inputs and that the solution relies
+// on, see `assume!`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedInput {
+    assumption: String,
+}
+
+impl UnsupportedInput {
+    pub fn new(assumption: impl Into<String>) -> Self {
+        Self { assumption: assumption.into() }
+    }
+
+    #[must_use]
+    pub fn assumption(&self) -> &str {
+        &self.assumption
+    }
+}
+
+impl Display for UnsupportedInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unsupported input, the solution assumes that {}",
+            self.assumption
+        )
+    }
+}
+
+impl Error for UnsupportedInput {}
+
+// Returns an `UnsupportedInput` error from the current function when the condition is false. The
+// assumption is described by a format string, or by the condition itself when there is none:
+//
+// aoc_common::assume!(visit == 1, "every start returns to the node it reaches after one loop");
+// aoc_common::assume!(width <= 198);
+#[macro_export]
+macro_rules! assume {
+    ($cond:expr $(,)?) => {
+        if !$cond {
+            return Err($crate::util::UnsupportedInput::new(stringify!($cond)).into());
+        }
+    };
+
+    ($cond:expr, $($assumption:tt)+) => {
+        if !$cond {
+            return Err($crate::util::UnsupportedInput::new(format!($($assumption)+)).into());
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnsupportedInput;
+    use crate::util::GenericResult;
+
+    fn edge(tiles: usize) -> GenericResult<usize> {
+        assume!(tiles.is_multiple_of(6), "the cube has {} faces", 6);
+        let face = tiles / 6;
+        assume!(face == 2500);
+        Ok(50)
+    }
+
+    #[test]
+    fn assume() {
+        assert_eq!(edge(15_000).unwrap(), 50);
+
+        let error = edge(7).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unsupported input, the solution assumes that the cube has 6 faces"
+        );
+
+        let error = edge(12).unwrap_err();
+        assert_eq!(
+            error
+                .downcast_ref::<UnsupportedInput>()
+                .map(UnsupportedInput::assumption),
+            Some("face == 2500")
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use super::CharParseError;
+
+// Directions on a grid where rows grow down, listed clockwise starting from up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    // (row, col) step of the direction
+    #[must_use]
+    pub const fn offset(self) -> (isize, isize) {
+        Direction8::from_4(self).offset()
+    }
+}
+
+// Arrows as they are drawn in puzzle inputs
+impl TryFrom<char> for Direction4 {
+    type Error = CharParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' => Ok(Self::Up),
+            '>' => Ok(Self::Right),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            ch => Err(CharParseError::new("Direction ('^', '>', 'v' or '<')", ch)),
+        }
+    }
+}
+
+// `Direction4` with diagonals in between, turns are by 45 degrees
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    #[must_use]
+    pub const fn from_4(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    // (row, col) step of the direction
+    #[must_use]
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (-1, 0),
+            Self::UpRight => (-1, 1),
+            Self::Right => (0, 1),
+            Self::DownRight => (1, 1),
+            Self::Down => (1, 0),
+            Self::DownLeft => (1, -1),
+            Self::Left => (0, -1),
+            Self::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Self::from_4(direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction4, Direction8};
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction4::Right.opposite(), Direction4::Left);
+        assert_eq!(Direction4::Down.offset(), (1, 0));
+
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+        assert_eq!(Direction8::from(Direction4::Left), Direction8::Left);
+    }
+}
@@ -0,0 +1,93 @@
+use std::io::{self, Write};
+
+use super::VecMatrix;
+
+// Netpbm images, one pixel per cell. These open in most image viewers and are simple enough to
+// write without dependencies, e.g. to dump simulation frames:
+//
+// grid.write_pbm(File::create(format!("frames/{step:05}.pbm"))?, |&tile| tile == Tile::Wall)?;
+impl<T> VecMatrix<T> {
+    // Black and white (P4), `is_set` cells are black
+    pub fn write_pbm(&self, mut out: impl Write, is_set: impl Fn(&T) -> bool) -> io::Result<()> {
+        self.check_full_rows()?;
+        writeln!(out, "P4\n{} {}", self.width(), self.height())?;
+
+        for row in self.rows() {
+            // Each row is padded to whole bytes
+            for chunk in row.chunks(8) {
+                let byte = chunk.iter().enumerate().fold(0u8, |acc, (bit, cell)| {
+                    acc | (u8::from(is_set(cell)) << (7 - bit))
+                });
+                out.write_all(&[byte])?;
+            }
+        }
+
+        out.flush()
+    }
+
+    // Grayscale (P5), 0 is black and 255 is white
+    pub fn write_pgm(&self, mut out: impl Write, gray: impl Fn(&T) -> u8) -> io::Result<()> {
+        self.check_full_rows()?;
+        writeln!(out, "P5\n{} {}\n255", self.width(), self.height())?;
+
+        let data = self.iter().map(gray).collect::<Vec<_>>();
+        out.write_all(&data)?;
+        out.flush()
+    }
+
+    // Colour (P6), `rgb` returns red, green and blue
+    pub fn write_ppm(&self, mut out: impl Write, rgb: impl Fn(&T) -> [u8; 3]) -> io::Result<()> {
+        self.check_full_rows()?;
+        writeln!(out, "P6\n{} {}\n255", self.width(), self.height())?;
+
+        let data = self.iter().flat_map(rgb).collect::<Vec<_>>();
+        out.write_all(&data)?;
+        out.flush()
+    }
+
+    // The header only has room for whole rows
+    fn check_full_rows(&self) -> io::Result<()> {
+        if self.len() == self.width() * self.height() {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the last row of the matrix is not full",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::VecMatrix;
+
+    #[test]
+    fn netpbm() {
+        let matrix = VecMatrix::with_data((0..18).collect::<Vec<u8>>(), 9);
+
+        let mut pbm = vec![];
+        matrix.write_pbm(&mut pbm, |&cell| cell % 2 == 0).unwrap();
+        assert_eq!(pbm, b"P4\n9 2\n\xAA\x80\x55\x00");
+
+        let mut pgm = vec![];
+        matrix.write_pgm(&mut pgm, |&cell| cell * 10).unwrap();
+        assert_eq!(pgm.len(), b"P5\n9 2\n255\n".len() + 18);
+        assert_eq!(pgm.last(), Some(&170));
+
+        let mut ppm = vec![];
+        matrix.write_ppm(&mut ppm, |&cell| [cell, 0, 255]).unwrap();
+        assert!(ppm.starts_with(b"P6\n9 2\n255\n"));
+        assert_eq!(ppm[ppm.len() - 3..], [17, 0, 255]);
+    }
+
+    #[test]
+    fn partial_row() {
+        let matrix = VecMatrix::with_data((0..5).collect::<Vec<u8>>(), 3);
+
+        let mut pgm = vec![];
+        let error = matrix.write_pgm(&mut pgm, |&cell| cell).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        assert!(pgm.is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+use std::{error::Error, fmt::Display};
+
+// An error located in the puzzle input. `Solution` fills in the line number and the line itself
+// when they are not known at the place where the error happens, and renders them as
+//
+// line 3, column 5: expected '#' or '.', found 'x'
+//   |
+// 3 | ##..x.#
+//   |     ^
+#[derive(Debug)]
+pub struct InputError {
+    line: Option<usize>,
+    column: Option<usize>,
+    text: Option<String>,
+    error: Box<dyn Error>,
+}
+
+impl InputError {
+    pub fn new(error: impl Into<Box<dyn Error>>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            text: None,
+            error: error.into(),
+        }
+    }
+
+    // 1-based line number
+    #[must_use]
+    pub const fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    // 0-based byte offset within the line
+    #[must_use]
+    pub const fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    #[must_use]
+    pub const fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(line) = self.line else {
+            return write!(f, "{}", self.error);
+        };
+
+        // Columns are shown in chars, the byte offset is kept when the line is not known
+        let column = self.column.map(|column| {
+            self.text
+                .as_ref()
+                .and_then(|text| text.get(..column))
+                .map_or(column, |before| before.chars().count())
+        });
+
+        match column {
+            Some(column) => writeln!(f, "line {line}, column {}: {}", column + 1, self.error)?,
+            None => writeln!(f, "line {line}: {}", self.error)?,
+        }
+
+        let Some(text) = &self.text else {
+            return Ok(());
+        };
+
+        let gutter = " ".repeat(line.to_string().len());
+        writeln!(f, "{gutter} |")?;
+        write!(f, "{line} | {text}")?;
+        if let Some(column) = column {
+            write!(f, "\n{gutter} | {:column$}^", "")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+#[derive(Debug)]
+pub struct CharParseError {
+    expected: &'static str,
+    actual: char,
+}
+
+impl CharParseError {
+    #[must_use]
+    pub const fn new(expected: &'static str, actual: char) -> Self {
+        Self { expected, actual }
+    }
+}
+
+impl Display for CharParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected {}, found {:?}", self.expected, self.actual)
+    }
+}
+
+impl Error for CharParseError {}
+
+// Converts every char of a line, failures point at the column of the offending char
+pub fn parse_chars<T>(line: String) -> impl Iterator<Item = Result<T, InputError>>
+where
+    T: TryFrom<char>,
+    T::Error: Error + 'static,
+{
+    // The iterator owns the line, so it walks the chars by their byte offset, which is also the
+    // column `InputError` expects
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        let column = offset;
+        let ch = line[column..].chars().next()?;
+        offset += ch.len_utf8();
+        Some(T::try_from(ch).map_err(|e| InputError::new(e).at_column(column)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputError, parse_chars};
+    use crate::util::Direction4;
+
+    #[test]
+    fn render() {
+        let error = InputError::new("bad tile")
+            .at_line(12)
+            .at_column(3)
+            .with_text("#..x#");
+
+        assert_eq!(
+            error.to_string(),
+            "line 12, column 4: bad tile\n   |\n12 | #..x#\n   |    ^"
+        );
+        assert_eq!(InputError::new("bad tile").to_string(), "bad tile");
+    }
+
+    #[test]
+    fn non_ascii_chars() {
+        let mut cells = parse_chars::<Direction4>("^→v".to_owned());
+        assert_eq!(cells.next().unwrap().unwrap(), Direction4::Up);
+
+        let error = cells.next().unwrap().unwrap_err();
+        assert_eq!(
+            error.at_line(1).with_text("^→v").to_string(),
+            "line 1, column 2: expected Direction ('^', '>', 'v' or '<'), found '→'\n  |\n1 | ^→v\n  |  ^"
+        );
+        assert_eq!(cells.next().unwrap().unwrap(), Direction4::Down);
+        assert!(cells.next().is_none());
+    }
+}
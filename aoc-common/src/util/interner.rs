@@ -0,0 +1,93 @@
+use std::{collections::HashMap, ops::Index};
+
+// Dense ids for names, given out in the order the names are first seen
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    ids: HashMap<String, usize>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Well-known names take the first ids in the given order, so that they can be constants:
+    // `Interner::with_names(["root", "humn"])` makes "root" 0 and "humn" 1
+    pub fn with_names<'n>(names: impl IntoIterator<Item = &'n str>) -> Self {
+        let mut interner = Self::new();
+        for name in names {
+            interner.intern(name);
+        }
+        interner
+    }
+
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.to_owned());
+        self.ids.insert(name.to_owned(), id);
+        id
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    #[must_use]
+    pub fn name(&self, id: usize) -> Option<&str> {
+        self.names.get(id).map(String::as_str)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.names.iter().map(String::as_str).enumerate()
+    }
+}
+
+impl Index<usize> for Interner {
+    type Output = str;
+
+    fn index(&self, id: usize) -> &Self::Output {
+        &self.names[id]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interner;
+    use crate::util::Lexer;
+
+    #[test]
+    fn intern() {
+        let mut interner = Interner::with_names(["root", "humn"]);
+        assert_eq!(interner.intern("humn"), 1);
+        assert_eq!(interner.intern("abcd"), 2);
+        assert_eq!(interner.get("abcd"), Some(2));
+        assert_eq!(interner.get("zzzz"), None);
+        assert_eq!(&interner[0], "root");
+        assert_eq!(interner.len(), 3);
+
+        let mut lexer = Lexer::of("AAA = (BBB, root)");
+        assert_eq!(lexer.interned(&mut interner), Ok(3));
+        lexer.literal(" = (").unwrap();
+        assert_eq!(lexer.interned(&mut interner), Ok(4));
+        lexer.literal(", ").unwrap();
+        assert_eq!(lexer.interned(&mut interner), Ok(0));
+        assert_eq!(interner.name(4), Some("BBB"));
+    }
+}
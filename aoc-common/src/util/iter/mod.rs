@@ -1,11 +1,13 @@
 mod end_on_err;
 mod groups;
 mod recursive;
+mod sections;
 mod windows_cycle;
 
 pub use end_on_err::EndOnErr;
 pub use groups::Groups;
 pub use recursive::Recursive;
+pub use sections::{Section, SectionError, Sections};
 pub use windows_cycle::WindowsCycle;
 
 pub trait IteratorExtended: Iterator + Sized {
@@ -20,6 +22,13 @@ pub trait IteratorExtended: Iterator + Sized {
     {
         Recursive::new(self, func)
     }
+
+    fn sections(self) -> Sections<Self>
+    where
+        Self: Iterator<Item = String>,
+    {
+        self.into()
+    }
 }
 
 impl<T: Iterator> IteratorExtended for T {}
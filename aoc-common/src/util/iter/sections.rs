@@ -0,0 +1,161 @@
+use std::{error::Error, fmt::Display, iter::Peekable};
+
+use crate::util::GenericResult;
+
+// Splits lines into sections separated by blank lines. Sections are either collected with
+// `Iterator::next`, or read line by line with `section`, `parse` and `header_body`
+pub struct Sections<I: Iterator<Item = String>> {
+    lines: Peekable<I>,
+    index: usize,
+}
+
+impl<I: Iterator<Item = String>> Sections<I> {
+    // Number of sections started so far
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    // Lines of the next section, `None` once the input is over. The blank line after the section is
+    // consumed together with it
+    pub fn section(&mut self) -> Option<Section<'_, I>> {
+        self.lines.peek()?;
+        self.index += 1;
+        Some(Section { lines: &mut self.lines, ended: false })
+    }
+
+    // Parses the next section, whatever `func` leaves unread is skipped. Errors, including a missing
+    // section, carry the 1-based index of the section
+    pub fn parse<T>(
+        &mut self,
+        func: impl FnOnce(&mut Section<'_, I>) -> GenericResult<T>,
+    ) -> Result<T, SectionError> {
+        let index = self.index + 1;
+        let mut section = self
+            .section()
+            .ok_or_else(|| SectionError::new(index, "the input ended before this section"))?;
+
+        let ret = func(&mut section).map_err(|e| SectionError::new(index, e))?;
+        section.for_each(drop);
+        Ok(ret)
+    }
+
+    // A section made of a header line followed by body lines, e.g. "seed-to-soil map:" and the
+    // mappings below it
+    pub fn header_body<H, B>(
+        &mut self,
+        header: impl FnOnce(&str) -> GenericResult<H>,
+        mut body: impl FnMut(&str) -> GenericResult<B>,
+    ) -> Result<(H, Vec<B>), SectionError> {
+        self.parse(|section| {
+            let header = header(&section.next().unwrap_or_default())?;
+            let body = section
+                .map(|line| body(&line))
+                .collect::<GenericResult<Vec<_>>>()?;
+            Ok((header, body))
+        })
+    }
+}
+
+impl<I: Iterator<Item = String>> Iterator for Sections<I> {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.section().map(Iterator::collect)
+    }
+}
+
+impl<I: Iterator<Item = String>> From<I> for Sections<I> {
+    fn from(lines: I) -> Self {
+        Self { lines: lines.peekable(), index: 0 }
+    }
+}
+
+pub struct Section<'s, I: Iterator<Item = String>> {
+    lines: &'s mut Peekable<I>,
+    ended: bool,
+}
+
+impl<I: Iterator<Item = String>> Iterator for Section<'_, I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ended {
+            return None;
+        }
+
+        let line = self.lines.next().filter(|line| !line.is_empty());
+        self.ended = line.is_none();
+        line
+    }
+}
+
+#[derive(Debug)]
+pub struct SectionError {
+    section: usize,
+    error: Box<dyn Error>,
+}
+
+impl SectionError {
+    pub fn new(section: usize, error: impl Into<Box<dyn Error>>) -> Self {
+        Self { section, error: error.into() }
+    }
+
+    // 1-based index of the section
+    #[must_use]
+    pub const fn section(&self) -> usize {
+        self.section
+    }
+}
+
+impl Display for SectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "section {}: {}", self.section, self.error)
+    }
+}
+
+impl Error for SectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::iter::IteratorExtended;
+
+    fn lines(s: &str) -> impl Iterator<Item = String> {
+        s.lines().map(String::from)
+    }
+
+    #[test]
+    fn collect() {
+        let sections = lines("a\nb\n\nc\n\n\nd").sections().collect::<Vec<_>>();
+        assert_eq!(sections, [vec!["a", "b"], vec!["c"], vec![], vec!["d"]]);
+    }
+
+    #[test]
+    fn parse() {
+        let mut sections = lines("seeds: 1\n\nmap:\n1 2\n3 4").sections();
+
+        let seeds = sections
+            .parse(|section| Ok(section.next().unwrap()))
+            .unwrap();
+        assert_eq!(seeds, "seeds: 1");
+
+        let (header, body) = sections
+            .header_body(
+                |header| Ok(header.len()),
+                |line| Ok(line.split(' ').count()),
+            )
+            .unwrap();
+        assert_eq!((header, body), (4, vec![2, 2]));
+
+        let error = sections.parse(|_| Ok(())).unwrap_err();
+        assert_eq!(error.section(), 3);
+        assert_eq!(
+            error.to_string(),
+            "section 3: the input ended before this section"
+        );
+    }
+}
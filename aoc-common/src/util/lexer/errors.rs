@@ -1,38 +1,114 @@
-use std::num::{IntErrorKind, ParseIntError};
+use std::{
+    fmt::{self, Display},
+    num::{IntErrorKind, ParseIntError},
+};
 
 use super::Lexer;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Lexeme {
     EOL,
     Literal(String),
     Number,
     Symbol,
     Symbols(usize),
+    OneOf(Vec<String>),
+    Digits { radix: u32 },
+    Float,
+    Identifier,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ErrorKind {
     UnexpectedEOL { expected: Lexeme },
     UnexpectedSymbol { expected: Lexeme, at: usize },
     ParseIntError { at: usize, kind: IntErrorKind },
+    NonAscii { at: usize },
+    ParseError { at: usize, message: String },
 }
 
-impl std::fmt::Display for ErrorKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+impl Display for Lexeme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EOL => f.write_str("end of line"),
+            Self::Literal(literal) => write!(f, "literal `{literal}`"),
+            Self::Number => f.write_str("a number"),
+            Self::Symbol => f.write_str("a symbol"),
+            Self::Symbols(len) => write!(f, "{len} symbols"),
+            Self::OneOf(literals) => {
+                f.write_str("one of ")?;
+                for (idx, literal) in literals.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "`{literal}`")?;
+                }
+                Ok(())
+            }
+            Self::Digits { radix } => write!(f, "base {radix} digits"),
+            Self::Float => f.write_str("a decimal number"),
+            Self::Identifier => f.write_str("an identifier"),
+        }
+    }
+}
+
+const fn int_error_message(kind: IntErrorKind) -> &'static str {
+    match kind {
+        IntErrorKind::Empty => "empty number",
+        IntErrorKind::InvalidDigit => "invalid digit in number",
+        IntErrorKind::PosOverflow => "number is too large for its type",
+        IntErrorKind::NegOverflow => "number is too small for its type",
+        IntErrorKind::Zero => "number cannot be zero",
+        _ => "invalid number",
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEOL { expected } => write!(f, "expected {expected}, found end of line"),
+            Self::UnexpectedSymbol { expected, .. } => write!(f, "expected {expected}"),
+            Self::ParseIntError { kind, .. } => f.write_str(int_error_message(*kind)),
+            Self::NonAscii { .. } => f.write_str("non-ASCII input, `Lexer::utf8` is needed for it"),
+            Self::ParseError { message, .. } => write!(f, "invalid value: {message}"),
+        }
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Error {
     str: String,
     kind: ErrorKind,
+    context: Vec<String>,
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+// "in register A: expected literal `: `, found `=`". The alternate form `{:#}` also shows the input
+// with a caret under the failure, for when nothing else shows where the input came from
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.context.is_empty() {
+            let context = self.context.iter().rev().map(String::as_str);
+            write!(f, "in {}: ", context.collect::<Vec<_>>().join(" > "))?;
+        }
+
+        let found = self.str[self.position()..].chars().next();
+        match (&self.kind, found) {
+            (ErrorKind::UnexpectedSymbol { .. } | ErrorKind::NonAscii { .. }, Some(ch)) => {
+                write!(f, "{}, found `{ch}`", self.kind)?;
+            }
+            _ => write!(f, "{}", self.kind)?,
+        }
+
+        if f.alternate() {
+            write!(
+                f,
+                "\n  |\n  | {}\n  | {:column$}^",
+                self.str,
+                "",
+                column = self.char_position()
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -40,27 +116,94 @@ impl std::error::Error for Error {}
 
 impl Error {
     #[must_use]
-    pub fn eol(lexer: &Lexer<'_>, expected: Lexeme) -> Self {
-        Self {
-            str: lexer.s.to_owned(),
-            kind: ErrorKind::UnexpectedEOL { expected },
+    pub const fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    // The whole string given to the lexer
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.str
+    }
+
+    // Byte offset in `input()` at which lexing failed
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        match self.kind {
+            ErrorKind::UnexpectedEOL { .. } => self.str.len(),
+            ErrorKind::UnexpectedSymbol { at, .. }
+            | ErrorKind::ParseIntError { at, .. }
+            | ErrorKind::NonAscii { at }
+            | ErrorKind::ParseError { at, .. } => at,
         }
     }
 
+    // What was being parsed, innermost first, see `Lexer::context`
     #[must_use]
-    pub fn symbol(lexer: &Lexer<'_>, expected: Lexeme) -> Self {
-        Self {
+    pub fn context(&self) -> &[String] {
+        &self.context
+    }
+
+    #[must_use]
+    pub fn with_context(mut self, name: &str) -> Self {
+        self.context.push(name.to_owned());
+        self
+    }
+
+    #[must_use]
+    pub fn char_position(&self) -> usize {
+        self.str[..self.position()].chars().count()
+    }
+
+    // A failure that was backtracked from earlier is more useful when it got further
+    fn new(lexer: &Lexer<'_>, kind: ErrorKind) -> Self {
+        let error = Self {
             str: lexer.s.to_owned(),
-            kind: ErrorKind::UnexpectedSymbol { expected, at: lexer.pos },
+            kind,
+            context: vec![],
+        };
+        match &lexer.furthest {
+            Some(furthest) if furthest.position() > error.position() => (**furthest).clone(),
+            _ => error,
         }
     }
 
+    #[must_use]
+    pub fn eol(lexer: &Lexer<'_>, expected: Lexeme) -> Self {
+        Self::new(lexer, ErrorKind::UnexpectedEOL { expected })
+    }
+
+    #[must_use]
+    pub fn symbol(lexer: &Lexer<'_>, expected: Lexeme) -> Self {
+        Self::new(
+            lexer,
+            ErrorKind::UnexpectedSymbol { expected, at: lexer.pos },
+        )
+    }
+
+    #[must_use]
+    pub fn non_ascii(lexer: &Lexer<'_>, at: usize) -> Self {
+        Self::new(lexer, ErrorKind::NonAscii { at })
+    }
+
     #[must_use]
     pub fn parse_error(lexer: &Lexer<'_>, error: &ParseIntError) -> Self {
-        Self {
-            str: lexer.s.to_owned(),
-            kind: ErrorKind::ParseIntError { at: lexer.pos, kind: *error.kind() },
-        }
+        Self::new(
+            lexer,
+            ErrorKind::ParseIntError { at: lexer.pos, kind: *error.kind() },
+        )
+    }
+
+    // For `FromStr` errors other than `ParseIntError` only the message is kept
+    #[must_use]
+    pub fn parse_message(lexer: &Lexer<'_>, error: &impl std::fmt::Display) -> Self {
+        Self::new(
+            lexer,
+            ErrorKind::ParseError {
+                at: lexer.pos,
+                message: error.to_string(),
+            },
+        )
     }
 }
 
@@ -1,34 +1,75 @@
 use std::{num::ParseIntError, str::FromStr};
 
+use super::Interner;
+
 mod errors;
 pub use errors::{Error, ErrorKind, Lexeme, Result};
 
+mod numbers;
+pub use numbers::Integer;
+
+mod scan;
+pub use scan::{Scan, Scanner};
+
+// Steps over whole chars. Positions are byte offsets into the lexed string, `take()` and
+// `symbol()` count chars
 #[derive(Clone, Debug)]
 pub struct Lexer<'a> {
     s: &'a str,
     pos: usize,
+    ascii: bool,
+    // Furthest failure that was backtracked from, reported instead of errors that happen earlier
+    furthest: Option<Box<Error>>,
 }
 
 #[allow(dead_code)]
 impl<'a> Lexer<'a> {
+    // ASCII-only mode, consuming anything else fails with `ErrorKind::NonAscii`
     #[must_use]
-    pub fn of(s: &'a str) -> Self {
-        assert!(
-            s.is_ascii(),
-            "This lexer only works with ASCII strings, sorry"
-        );
-        Self { s, pos: 0 }
+    pub const fn of(s: &'a str) -> Self {
+        Self {
+            s,
+            pos: 0,
+            ascii: true,
+            furthest: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn utf8(s: &'a str) -> Self {
+        Self {
+            s,
+            pos: 0,
+            ascii: false,
+            furthest: None,
+        }
     }
 
     fn slice(&self) -> &'a str {
         &self.s[self.pos..]
     }
 
+    // `len` bytes from the current position, if they can be consumed in this mode
+    fn checked(&self, len: usize) -> Result<&'a str> {
+        let ret = &self.slice()[..len];
+        if self.ascii
+            && let Some(offset) = ret.bytes().position(|b| !b.is_ascii())
+        {
+            return Err(Error::non_ascii(self, self.pos + offset));
+        }
+        Ok(ret)
+    }
+
     #[must_use]
     pub const fn position(&self) -> usize {
         self.pos
     }
 
+    #[must_use]
+    pub fn char_position(&self) -> usize {
+        self.s[..self.pos].chars().count()
+    }
+
     pub fn end(&self) -> Result<()> {
         if self.slice().is_empty() {
             Ok(())
@@ -42,42 +83,45 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn symbol(&mut self) -> Result<char> {
-        if self.slice().is_empty() {
-            return Err(Error::eol(self, Lexeme::Symbol));
-        }
-
-        let ret =
-            self.slice()
-                .bytes()
-                .next()
-                .expect("??? There was at least one byte, I checked, I swear") as char;
+        let ret = self
+            .slice()
+            .chars()
+            .next()
+            .ok_or_else(|| Error::eol(self, Lexeme::Symbol))?;
 
-        self.shift(1);
+        self.checked(ret.len_utf8())?;
+        self.shift(ret.len_utf8());
         Ok(ret)
     }
 
     pub fn take(&mut self, len: usize) -> Result<&'a str> {
-        if self.slice().len() < len {
-            return Err(Error::eol(self, Lexeme::Symbols(len)));
-        }
-
-        let ret = &self.slice()[..len];
-        self.shift(len);
+        let end = self
+            .slice()
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain(std::iter::once(self.slice().len()))
+            .nth(len)
+            .ok_or_else(|| Error::eol(self, Lexeme::Symbols(len)))?;
+
+        let ret = self.checked(end)?;
+        self.shift(end);
         Ok(ret)
     }
 
-    pub fn take_while(&mut self, func: impl Fn(char) -> bool) -> Result<&'a str> {
-        let pos = self
+    pub fn take_while(&mut self, mut func: impl FnMut(char) -> bool) -> Result<&'a str> {
+        let end = self
             .slice()
-            .bytes()
-            .position(|ch| !func(ch as char))
-            .unwrap_or_else(|| self.slice().len());
+            .char_indices()
+            .find(|&(_, ch)| !func(ch))
+            .map_or_else(|| self.slice().len(), |(idx, _)| idx);
 
-        self.take(pos)
+        let ret = self.checked(end)?;
+        self.shift(end);
+        Ok(ret)
     }
 
     pub fn take_rest(&mut self) -> Result<&'a str> {
-        let ret = self.slice();
+        let ret = self.checked(self.slice().len())?;
         self.shift(ret.len());
         Ok(ret)
     }
@@ -106,32 +150,53 @@ impl<'a> Lexer<'a> {
             .find(literal)
             .ok_or_else(|| Error::eol(self, Lexeme::Literal(literal.to_owned())))?;
 
-        let ret = self.take(pos)?;
+        let ret = self.checked(pos)?;
 
-        self.shift(literal.len());
+        self.shift(pos + literal.len());
         Ok(ret)
     }
 
-    fn digit_string(&self) -> Result<usize> {
+    // ASCII letters, digits and underscores, at least one of them
+    pub fn identifier(&mut self) -> Result<&'a str> {
+        let pos = self.digit_string(
+            |ch| ch.is_ascii_alphanumeric() || ch == b'_',
+            Lexeme::Identifier,
+        )?;
+
+        let ret = &self.slice()[..pos];
+        self.shift(pos);
+        Ok(ret)
+    }
+
+    // Reads an identifier straight into its id
+    pub fn interned(&mut self, interner: &mut Interner) -> Result<usize> {
+        self.identifier().map(|name| interner.intern(name))
+    }
+
+    fn digit_string(&self, is_digit: impl Fn(u8) -> bool, expected: Lexeme) -> Result<usize> {
         if self.slice().is_empty() {
-            return Err(Error::eol(self, Lexeme::Number));
+            return Err(Error::eol(self, expected));
         }
 
         let pos = self
             .slice()
             .bytes()
-            .position(|ch| !ch.is_ascii_digit())
+            .position(|ch| !is_digit(ch))
             .unwrap_or_else(|| self.slice().len());
 
         if pos == 0 {
-            return Err(Error::symbol(self, Lexeme::Number));
+            return Err(Error::symbol(self, expected));
         }
 
         Ok(pos)
     }
 
+    fn decimal_digits(&self) -> Result<usize> {
+        self.digit_string(|ch| ch.is_ascii_digit(), Lexeme::Number)
+    }
+
     pub fn unsigned_number<Num: FromStr<Err = ParseIntError>>(&mut self) -> Result<Num> {
-        let pos = self.digit_string()?;
+        let pos = self.decimal_digits()?;
 
         let ret = self.slice()[..pos]
             .parse()
@@ -146,7 +211,7 @@ impl<'a> Lexer<'a> {
             let offset = usize::from(self.slice().starts_with('-'));
             let mut offsetted = self.clone();
             offsetted.shift(offset);
-            offset + offsetted.digit_string()?
+            offset + offsetted.decimal_digits()?
         };
 
         let ret = self.slice()[..pos]
@@ -157,15 +222,116 @@ impl<'a> Lexer<'a> {
         Ok(ret)
     }
 
-    pub fn reset_on_error<T>(&mut self, func: impl Fn(&mut Self) -> Result<T>) -> Result<T> {
-        let reset = self.clone();
+    // Failures inside `func` only compete for the furthest error until it returns
+    pub fn reset_on_error<T>(&mut self, func: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let reset = self.pos;
+        let furthest = self.furthest.clone();
         let ret = func(self);
         if ret.is_err() {
-            *self = reset;
+            self.pos = reset;
+        }
+        self.furthest = furthest;
+        ret
+    }
+
+    // Names what `func` parses in its errors, e.g. "in register A: expected a number, found `x`"
+    pub fn context<T>(
+        &mut self,
+        name: &str,
+        func: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        func(self).map_err(|e| e.with_context(name))
+    }
+
+    pub fn opt<T>(&mut self, item: impl FnOnce(&mut Self) -> Result<T>) -> Option<T> {
+        self.reset_on_error(item).ok()
+    }
+
+    // Stops on the first item that fails or doesn't consume anything
+    pub fn many0<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Vec<T> {
+        let mut ret = Vec::new();
+        loop {
+            let start = self.pos;
+            let Ok(value) = self.reset_on_error(&mut item) else {
+                break;
+            };
+
+            ret.push(value);
+            if self.pos == start {
+                break;
+            }
         }
         ret
     }
 
+    pub fn many1<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        let first = self.reset_on_error(&mut item)?;
+        let mut ret = vec![first];
+        ret.extend(self.many0(item));
+        Ok(ret)
+    }
+
+    // One or more items, a separator that is not followed by an item is left unconsumed
+    pub fn separated_by<T>(
+        &mut self,
+        mut sep: impl FnMut(&mut Self) -> Result<()>,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let first = self.reset_on_error(&mut item)?;
+        let mut ret = vec![first];
+        ret.extend(self.many0(|lexer| {
+            sep(lexer)?;
+            item(lexer)
+        }));
+        Ok(ret)
+    }
+
+    // The first of the literals that matches, in the given order
+    pub fn one_of<const N: usize>(&mut self, literals: [&str; N]) -> Result<&'a str> {
+        let Some(literal) = literals.iter().find(|lit| self.slice().starts_with(**lit)) else {
+            let expected = Lexeme::OneOf(literals.iter().map(|&lit| lit.to_owned()).collect());
+            return Err(if self.slice().is_empty() {
+                Error::eol(self, expected)
+            } else {
+                Error::symbol(self, expected)
+            });
+        };
+
+        self.take(literal.chars().count())
+    }
+
+    // The result of the first alternative that succeeds, otherwise the error that got the furthest
+    pub fn alt<T, const N: usize>(
+        &mut self,
+        alternatives: [fn(&mut Self) -> Result<T>; N],
+    ) -> Result<T> {
+        assert!(N > 0, "alt() needs at least one alternative");
+
+        // Errors of the later alternatives are replaced by an earlier one that got further, see
+        // `Error::new`
+        let saved = self.furthest.clone();
+        for alternative in alternatives {
+            match self.reset_on_error(alternative) {
+                Ok(value) => {
+                    self.furthest = saved;
+                    return Ok(value);
+                }
+                Err(e) => {
+                    if self
+                        .furthest
+                        .as_ref()
+                        .is_none_or(|f| e.position() > f.position())
+                    {
+                        self.furthest = Some(Box::new(e));
+                    }
+                }
+            }
+        }
+
+        let error = std::mem::replace(&mut self.furthest, saved);
+        Err(*error.expect("an alternative has failed"))
+    }
+
     pub const fn chain<'c>(&'c mut self) -> Chain<'a, 'c> {
         Chain { lexer: self }
     }
@@ -218,4 +384,142 @@ impl<'a> Chain<'a, '_> {
         *var = self.lexer.number()?;
         Ok(self)
     }
+
+    pub fn radix_number<Num: Integer>(self, var: &mut Num, radix: u32) -> Result<Self> {
+        *var = self.lexer.radix_number(radix)?;
+        Ok(self)
+    }
+
+    pub fn float<Num>(self, var: &mut Num) -> Result<Self>
+    where
+        Num: FromStr,
+        Num::Err: std::fmt::Display,
+    {
+        *var = self.lexer.float()?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorKind, Lexeme, Lexer, Result};
+
+    #[test]
+    fn utf8() {
+        let mut lexer = Lexer::utf8("\u{feff}┌─┐ 42");
+
+        assert_eq!(lexer.symbol(), Ok('\u{feff}'));
+        assert_eq!(lexer.take(2), Ok("┌─"));
+        assert_eq!(lexer.take_while(|ch| ch != ' '), Ok("┐"));
+        assert_eq!((lexer.position(), lexer.char_position()), (12, 4));
+
+        lexer.whitespace().unwrap();
+        assert_eq!(lexer.unsigned_number::<u32>(), Ok(42));
+        assert_eq!(lexer.end(), Ok(()));
+    }
+
+    #[test]
+    fn ascii_rejects_unicode() {
+        let mut lexer = Lexer::of("ab┌");
+
+        assert_eq!(lexer.take_while(|ch| ch.is_ascii_alphabetic()), Ok("ab"));
+        assert_eq!(
+            lexer.take_rest().unwrap_err().kind(),
+            &ErrorKind::NonAscii { at: 2 }
+        );
+        assert_eq!(
+            lexer.symbol().unwrap_err().kind(),
+            &ErrorKind::NonAscii { at: 2 }
+        );
+    }
+
+    #[test]
+    fn combinators() {
+        let mut lexer = Lexer::of("seeds: 1 2 3, -4, 5 noop");
+        lexer.literal("seeds:").unwrap();
+
+        let numbers = lexer.many1(|l| {
+            l.literal(" ")?;
+            l.unsigned_number::<u32>()
+        });
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+
+        lexer.literal(", ").unwrap();
+        let numbers = lexer.separated_by(|l| l.literal(", "), Lexer::number::<i32>);
+        assert_eq!(numbers, Ok(vec![-4, 5]));
+
+        assert_eq!(lexer.opt(|l| l.literal(",")), None);
+        assert_eq!(lexer.many0(|l| l.literal(",")), vec![]);
+        assert_eq!(lexer.one_of([" addx", " noop"]), Ok(" noop"));
+        assert_eq!(lexer.end(), Ok(()));
+    }
+
+    #[test]
+    fn furthest_failure() {
+        fn pair(lexer: &mut Lexer<'_>) -> Result<(u32, u32)> {
+            let first = lexer.unsigned_number()?;
+            lexer.literal(",")?;
+            Ok((first, lexer.unsigned_number()?))
+        }
+
+        // The second alternative fails right away, but the first one got further
+        let mut lexer = Lexer::of("12,x");
+        let error = lexer.alt([pair, |l| {
+            l.literal("(")?;
+            pair(l)
+        }]);
+        assert_eq!(
+            error.unwrap_err().kind(),
+            &ErrorKind::UnexpectedSymbol { expected: Lexeme::Number, at: 3 }
+        );
+        assert_eq!(lexer.position(), 0);
+
+        let mut lexer = Lexer::of("1,2 3,");
+        assert_eq!(
+            lexer.separated_by(|l| l.literal(" "), pair),
+            Ok(vec![(1, 2)])
+        );
+        // The failed item after " " is forgotten once `separated_by` returns
+        assert_eq!(
+            lexer.end().unwrap_err().kind(),
+            &ErrorKind::UnexpectedSymbol { expected: Lexeme::EOL, at: 3 }
+        );
+
+        let mut lexer = Lexer::of("(1,2 x");
+        let value = lexer.alt([pair, |l| {
+            l.literal("(")?;
+            pair(l)
+        }]);
+        assert_eq!(value, Ok((1, 2)));
+        assert_eq!(
+            lexer.end().unwrap_err().kind(),
+            &ErrorKind::UnexpectedSymbol { expected: Lexeme::EOL, at: 4 }
+        );
+    }
+
+    #[test]
+    fn display() {
+        let mut lexer = Lexer::of("Register A= 729");
+        let error = lexer
+            .context("register A", |l| {
+                l.literal("Register A")?;
+                l.literal(": ")
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "in register A: expected literal `: `, found `=`"
+        );
+        assert_eq!(
+            format!("{error:#}"),
+            "in register A: expected literal `: `, found `=`\n  |\n  | Register A= 729\n  |           ^"
+        );
+
+        let error = Lexer::of("12").literal("123").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected literal `123`, found end of line"
+        );
+    }
 }
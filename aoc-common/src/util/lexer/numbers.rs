@@ -0,0 +1,169 @@
+use std::{fmt::Display, num::ParseIntError, str::FromStr};
+
+use super::{Error, Lexeme, Lexer, Result};
+
+// Integers that can be read in any radix, see `Lexer::radix_number`
+pub trait Integer: FromStr<Err = ParseIntError> {
+    fn from_str_radix(s: &str, radix: u32) -> core::result::Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_integer {
+    ($($num:ty),*) => {
+        $(
+            impl Integer for $num {
+                fn from_str_radix(s: &str, radix: u32) -> core::result::Result<Self, ParseIntError> {
+                    <$num>::from_str_radix(s, radix)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+fn count_digits(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+impl Lexer<'_> {
+    // Unsigned digits in the given radix, without any prefix: "70c710" rather than "0x70c710"
+    pub fn radix_number<Num: Integer>(&mut self, radix: u32) -> Result<Num> {
+        let pos = self.digit_string(
+            |ch| char::from(ch).is_digit(radix),
+            Lexeme::Digits { radix },
+        )?;
+
+        let ret = Num::from_str_radix(&self.slice()[..pos], radix)
+            .map_err(|e| Error::parse_error(self, &e))?;
+
+        self.shift(pos);
+        Ok(ret)
+    }
+
+    pub fn hex_number<Num: Integer>(&mut self) -> Result<Num> {
+        self.radix_number(16)
+    }
+
+    pub fn octal_number<Num: Integer>(&mut self) -> Result<Num> {
+        self.radix_number(8)
+    }
+
+    pub fn binary_number<Num: Integer>(&mut self) -> Result<Num> {
+        self.radix_number(2)
+    }
+
+    // -?digits(.digits)?([eE][+-]?digits)?, the fraction and exponent are only taken when they
+    // have digits so that "1." leaves the dot alone
+    pub fn float<Num>(&mut self) -> Result<Num>
+    where
+        Num: FromStr,
+        Num::Err: Display,
+    {
+        let bytes = self.slice().as_bytes();
+        let sign = usize::from(bytes.first() == Some(&b'-'));
+        let mut pos = sign + count_digits(&bytes[sign..]);
+        let mut has_digits = pos > sign;
+
+        if bytes.get(pos) == Some(&b'.') {
+            let fraction = count_digits(&bytes[pos + 1..]);
+            if fraction > 0 {
+                pos += 1 + fraction;
+                has_digits = true;
+            }
+        }
+
+        if !has_digits {
+            let mut offsetted = self.clone();
+            offsetted.shift(sign);
+            return Err(if offsetted.slice().is_empty() {
+                Error::eol(&offsetted, Lexeme::Float)
+            } else {
+                Error::symbol(&offsetted, Lexeme::Float)
+            });
+        }
+
+        if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+            let exponent = pos + 1 + usize::from(matches!(bytes.get(pos + 1), Some(b'+' | b'-')));
+            let digits = count_digits(&bytes[exponent.min(bytes.len())..]);
+            if digits > 0 {
+                pos = exponent + digits;
+            }
+        }
+
+        let ret = self.slice()[..pos]
+            .parse()
+            .map_err(|e| Error::parse_message(self, &e))?;
+
+        self.shift(pos);
+        Ok(ret)
+    }
+
+    // The longest run of chars accepted by `func`, converted with `FromStr`. Nothing is consumed
+    // when the conversion fails
+    pub fn parse_with<T>(&mut self, func: impl FnMut(char) -> bool) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let start = self.pos;
+        let s = self.take_while(func)?;
+        s.parse().map_err(|e| {
+            self.pos = start;
+            Error::parse_message(self, &e)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::lexer::{ErrorKind, Lexeme, Lexer};
+
+    #[test]
+    fn radix() {
+        let mut lexer = Lexer::of("70c710)");
+        assert_eq!(lexer.hex_number::<u32>(), Ok(0x0070_c710));
+        assert_eq!(lexer.position(), 6);
+
+        assert_eq!(Lexer::of("1011").binary_number::<u8>(), Ok(0b1011));
+        assert_eq!(Lexer::of("0777").octal_number::<u16>(), Ok(0o777));
+
+        let error = Lexer::of("g1").hex_number::<u32>().unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ErrorKind::UnexpectedSymbol {
+                expected: Lexeme::Digits { radix: 16 },
+                at: 0
+            }
+        );
+    }
+
+    #[test]
+    fn float() {
+        let mut lexer = Lexer::of("-1.5e3 2. .25");
+        assert_eq!(lexer.float::<f64>(), Ok(-1500.0));
+        lexer.whitespace().unwrap();
+        assert_eq!(lexer.float::<f64>(), Ok(2.0));
+        assert_eq!(lexer.position(), 8);
+        lexer.literal(". ").unwrap();
+        assert_eq!(lexer.float::<f32>(), Ok(0.25));
+
+        let error = Lexer::of("-x").float::<f64>().unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ErrorKind::UnexpectedSymbol { expected: Lexeme::Float, at: 1 }
+        );
+    }
+
+    #[test]
+    fn parse_with() {
+        let mut lexer = Lexer::of("true,maybe");
+        assert_eq!(lexer.parse_with::<bool>(|ch| ch != ','), Ok(true));
+        lexer.literal(",").unwrap();
+
+        let error = lexer.parse_with::<bool>(|ch| ch != ',').unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::ParseError { at: 5, .. }));
+        assert_eq!(lexer.position(), 5);
+    }
+}
@@ -0,0 +1,202 @@
+use super::{Lexer, Result};
+
+// Values that `scan!` can read in place of a `{}`. `next` is the format text that follows the
+// placeholder, for values that don't have a natural end
+pub trait Scan<'a>: Sized {
+    fn scan(lexer: &mut Lexer<'a>, next: &str) -> Result<Self>;
+}
+
+macro_rules! impl_scan {
+    ($method:ident: $($num:ty),*) => {
+        $(
+            impl<'a> Scan<'a> for $num {
+                fn scan(lexer: &mut Lexer<'a>, _next: &str) -> Result<Self> {
+                    lexer.$method()
+                }
+            }
+        )*
+    };
+}
+
+impl_scan!(unsigned_number: u8, u16, u32, u64, u128, usize);
+impl_scan!(number: i8, i16, i32, i64, i128, isize);
+impl_scan!(float: f32, f64);
+
+impl<'a> Scan<'a> for char {
+    fn scan(lexer: &mut Lexer<'a>, _next: &str) -> Result<Self> {
+        lexer.symbol()
+    }
+}
+
+// A word ends on whitespace or on the first char of the text after the placeholder
+impl<'a> Scan<'a> for &'a str {
+    fn scan(lexer: &mut Lexer<'a>, next: &str) -> Result<Self> {
+        let stop = next.chars().next().filter(|ch| !ch.is_whitespace());
+        lexer.take_while(|ch| !ch.is_whitespace() && Some(ch) != stop)
+    }
+}
+
+impl<'a> Scan<'a> for String {
+    fn scan(lexer: &mut Lexer<'a>, next: &str) -> Result<Self> {
+        <&str>::scan(lexer, next).map(ToOwned::to_owned)
+    }
+}
+
+// Runtime part of `scan!`, walks the format string and the lexed line side by side
+pub struct Scanner<'a, 'f> {
+    lexer: Lexer<'a>,
+    format: &'f str,
+}
+
+impl<'a, 'f> Scanner<'a, 'f> {
+    #[must_use]
+    pub const fn new(s: &'a str, format: &'f str) -> Self {
+        Self { lexer: Lexer::of(s), format }
+    }
+
+    // Number of `{}` in a format, `scan!` checks it against the values when it expands
+    #[must_use]
+    pub const fn placeholders(format: &str) -> usize {
+        let bytes = format.as_bytes();
+        let mut count = 0;
+        let mut idx = 0;
+        while idx + 1 < bytes.len() {
+            if bytes[idx] == b'{' && bytes[idx + 1] == b'}' {
+                count += 1;
+                idx += 1;
+            }
+            idx += 1;
+        }
+        count
+    }
+
+    // A space in the format matches any amount of whitespace, including none
+    fn literal(&mut self, literal: &str) -> Result<()> {
+        for (idx, part) in literal.split(' ').enumerate() {
+            if idx > 0 {
+                self.lexer.whitespace()?;
+            }
+            self.lexer.literal(part)?;
+        }
+        Ok(())
+    }
+
+    pub fn value<T: Scan<'a>>(&mut self) -> Result<T> {
+        let (literal, rest) = self
+            .format
+            .split_once("{}")
+            .expect("scan! checks the number of `{}` when it expands");
+
+        self.literal(literal)?;
+        self.format = rest;
+
+        let next = rest.split("{}").next().unwrap_or_default();
+        T::scan(&mut self.lexer, next)
+    }
+
+    pub fn finish(mut self) -> Result<()> {
+        debug_assert!(
+            !self.format.contains("{}"),
+            "scan! checks the number of `{{}}` when it expands"
+        );
+
+        self.literal(self.format)?;
+        self.lexer.end()
+    }
+}
+
+// Parses a whole line according to a format string, every `{}` is read as the next value:
+//
+// let (x, y, dx, dy) = scan!(line, "p={},{} v={},{}" => (usize, usize, isize, isize))?;
+// let robot = scan!(line, "p={},{} v={},{}" => Robot { x, y, dx, dy })?;
+//
+// Returns a `lexer::Result`, with the same errors as the equivalent `Lexer` calls. A format with
+// a different number of `{}` than values doesn't compile
+#[macro_export]
+macro_rules! scan {
+    ($s:expr, $format:literal => ($($ty:ty),+ $(,)?)) => {
+        (|| {
+            $crate::scan!(@check $format, $($ty),+);
+            let mut scanner = $crate::util::lexer::Scanner::new($s, $format);
+            let ret = ($(scanner.value::<$ty>()?,)+);
+            scanner.finish()?;
+            Ok::<_, $crate::util::lexer::Error>(ret)
+        })()
+    };
+
+    ($s:expr, $format:literal => $name:ident { $($field:ident),+ $(,)? }) => {
+        (|| {
+            $crate::scan!(@check $format, $($field),+);
+            let mut scanner = $crate::util::lexer::Scanner::new($s, $format);
+            let ret = $name { $($field: scanner.value()?,)+ };
+            scanner.finish()?;
+            Ok::<_, $crate::util::lexer::Error>(ret)
+        })()
+    };
+
+    (@check $format:literal, $($value:tt),+) => {
+        const _: () = assert!(
+            $crate::util::lexer::Scanner::placeholders($format) == [$(stringify!($value)),+].len(),
+            "scan! format needs one `{{}}` per value",
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scanner;
+    use crate::util::lexer::{ErrorKind, Lexeme};
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Robot {
+        x: usize,
+        y: usize,
+        dx: isize,
+        dy: isize,
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(
+            scan!("p=0,4 v=3,-3", "p={},{} v={},{}" => (usize, usize, isize, isize)),
+            Ok((0, 4, 3, -3))
+        );
+        assert_eq!(
+            scan!("p=0,4 v=3,-3", "p={},{} v={},{}" => Robot { x, y, dx, dy }),
+            Ok(Robot { x: 0, y: 4, dx: 3, dy: -3 })
+        );
+        assert_eq!(
+            scan!("19, 13, 30 @ -2,  1, -2", "{}, {}, {} @ {}, {}, {}" => (i64, i64, i64, i64, i64, i64)),
+            Ok((19, 13, 30, -2, 1, -2))
+        );
+        assert_eq!(
+            scan!("move abc to d-e", "move {} to {}-{}" => (&str, String, char)),
+            Ok(("abc", "d".to_owned(), 'e'))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let error = scan!("p=0;4", "p={},{}" => (usize, usize)).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ErrorKind::UnexpectedSymbol {
+                expected: Lexeme::Literal(",".into()),
+                at: 3
+            }
+        );
+
+        let error = scan!("p=0,4 extra", "p={},{}" => (usize, usize)).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ErrorKind::UnexpectedSymbol { expected: Lexeme::EOL, at: 5 }
+        );
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(Scanner::placeholders("p={},{} v={},{}"), 4);
+        assert_eq!(Scanner::placeholders("{}{}"), 2);
+        assert_eq!(Scanner::placeholders("{ } }{ {"), 0);
+    }
+}
@@ -0,0 +1,228 @@
+use std::ops::{Index, IndexMut};
+
+use super::{MatrixIndex, VecMatrix};
+
+// One of the 8 ways to look at a matrix by rotating and mirroring it. A view index is first flipped
+// within the view, then transposed to get the index in the matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    transpose: bool,
+    flip_rows: bool,
+    flip_cols: bool,
+}
+
+impl Orientation {
+    pub const IDENTITY: Self = Self::new(false, false, false);
+    pub const TRANSPOSED: Self = Self::new(true, false, false);
+    pub const ROTATED_CW: Self = Self::new(true, false, true);
+    pub const ROTATED_180: Self = Self::new(false, true, true);
+    pub const ROTATED_CCW: Self = Self::new(true, true, false);
+    // Mirrored left to right
+    pub const FLIPPED_H: Self = Self::new(false, false, true);
+    // Mirrored top to bottom
+    pub const FLIPPED_V: Self = Self::new(false, true, false);
+
+    const fn new(transpose: bool, flip_rows: bool, flip_cols: bool) -> Self {
+        Self { transpose, flip_rows, flip_cols }
+    }
+
+    // (height, width) of a matrix of the given size when viewed this way
+    #[must_use]
+    pub const fn size(self, height: usize, width: usize) -> (usize, usize) {
+        if self.transpose { (width, height) } else { (height, width) }
+    }
+
+    // Matrix index for `idx` in a view of `height` x `width`
+    const fn source(self, idx: MatrixIndex, height: usize, width: usize) -> MatrixIndex {
+        let row = if self.flip_rows { height - 1 - idx.row } else { idx.row };
+        let col = if self.flip_cols { width - 1 - idx.col } else { idx.col };
+
+        if self.transpose {
+            MatrixIndex { row: col, col: row }
+        } else {
+            MatrixIndex { row, col }
+        }
+    }
+}
+
+// Borrows a matrix and indexes into its storage in another orientation, nothing is copied
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'m, T> {
+    matrix: &'m VecMatrix<T>,
+    orientation: Orientation,
+}
+
+#[derive(Debug)]
+pub struct MatrixViewMut<'m, T> {
+    matrix: &'m mut VecMatrix<T>,
+    orientation: Orientation,
+}
+
+impl<T> VecMatrix<T> {
+    #[must_use]
+    pub const fn view(&self, orientation: Orientation) -> MatrixView<'_, T> {
+        MatrixView { matrix: self, orientation }
+    }
+
+    pub const fn view_mut(&mut self, orientation: Orientation) -> MatrixViewMut<'_, T> {
+        MatrixViewMut { matrix: self, orientation }
+    }
+
+    #[must_use]
+    pub const fn transposed(&self) -> MatrixView<'_, T> {
+        self.view(Orientation::TRANSPOSED)
+    }
+
+    #[must_use]
+    pub const fn rotated_cw(&self) -> MatrixView<'_, T> {
+        self.view(Orientation::ROTATED_CW)
+    }
+
+    #[must_use]
+    pub const fn flipped_h(&self) -> MatrixView<'_, T> {
+        self.view(Orientation::FLIPPED_H)
+    }
+}
+
+// Read-only part shared by both views
+macro_rules! impl_view {
+    ($view:ident) => {
+        impl<T> $view<'_, T> {
+            #[must_use]
+            pub const fn orientation(&self) -> Orientation {
+                self.orientation
+            }
+
+            #[must_use]
+            pub const fn height(&self) -> usize {
+                self.size().0
+            }
+
+            #[must_use]
+            pub const fn width(&self) -> usize {
+                self.size().1
+            }
+
+            #[must_use]
+            pub fn get(&self, idx: MatrixIndex) -> Option<&T> {
+                self.source(idx).map(|idx| &self.matrix[idx])
+            }
+
+            pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+                (0..self.width()).map(move |col| &self[MatrixIndex { row, col }])
+            }
+
+            pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+                (0..self.height()).map(move |row| &self[MatrixIndex { row, col }])
+            }
+
+            pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+                (0..self.height()).map(|row| self.row(row))
+            }
+
+            pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+                (0..self.width()).map(|col| self.col(col))
+            }
+
+            // Row by row in the view's orientation
+            pub fn iter_enumerate(&self) -> impl Iterator<Item = (MatrixIndex, &T)> {
+                (0..self.height()).flat_map(move |row| {
+                    (0..self.width()).map(move |col| {
+                        let idx = MatrixIndex { row, col };
+                        (idx, &self[idx])
+                    })
+                })
+            }
+
+            #[must_use]
+            pub fn to_matrix(&self) -> VecMatrix<T>
+            where
+                T: Clone,
+            {
+                let data = self.rows().flatten().cloned().collect();
+                VecMatrix::with_data(data, self.width())
+            }
+
+            const fn size(&self) -> (usize, usize) {
+                self.orientation
+                    .size(self.matrix.height(), self.matrix.width())
+            }
+
+            const fn source(&self, idx: MatrixIndex) -> Option<MatrixIndex> {
+                let (height, width) = self.size();
+                if idx.row < height && idx.col < width {
+                    Some(self.orientation.source(idx, height, width))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<T> Index<MatrixIndex> for $view<'_, T> {
+            type Output = T;
+
+            fn index(&self, index: MatrixIndex) -> &Self::Output {
+                self.get(index).unwrap()
+            }
+        }
+    };
+}
+
+impl_view!(MatrixView);
+impl_view!(MatrixViewMut);
+
+impl<T> MatrixViewMut<'_, T> {
+    pub fn get_mut(&mut self, idx: MatrixIndex) -> Option<&mut T> {
+        self.source(idx).map(|idx| &mut self.matrix[idx])
+    }
+}
+
+impl<T> IndexMut<MatrixIndex> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, index: MatrixIndex) -> &mut Self::Output {
+        self.get_mut(index).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Orientation;
+    use crate::util::{MatrixIndex, VecMatrix};
+
+    // 1 2 3
+    // 4 5 6
+    fn matrix() -> VecMatrix<u8> {
+        VecMatrix::with_data(vec![1, 2, 3, 4, 5, 6], 3)
+    }
+
+    fn data(matrix: &VecMatrix<u8>, orientation: Orientation) -> Vec<u8> {
+        matrix.view(orientation).to_matrix().into_iter().collect()
+    }
+
+    #[test]
+    fn orientations() {
+        let matrix = matrix();
+        assert_eq!(data(&matrix, Orientation::IDENTITY), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(data(&matrix, Orientation::TRANSPOSED), [1, 4, 2, 5, 3, 6]);
+        assert_eq!(data(&matrix, Orientation::ROTATED_CW), [4, 1, 5, 2, 6, 3]);
+        assert_eq!(data(&matrix, Orientation::ROTATED_180), [6, 5, 4, 3, 2, 1]);
+        assert_eq!(data(&matrix, Orientation::ROTATED_CCW), [3, 6, 2, 5, 1, 4]);
+        assert_eq!(data(&matrix, Orientation::FLIPPED_H), [3, 2, 1, 6, 5, 4]);
+        assert_eq!(data(&matrix, Orientation::FLIPPED_V), [4, 5, 6, 1, 2, 3]);
+
+        let cols = matrix.cols().map(|col| col.copied().collect::<Vec<_>>());
+        assert_eq!(cols.collect::<Vec<_>>(), [[1, 4], [2, 5], [3, 6]]);
+
+        let rotated = matrix.rotated_cw();
+        assert_eq!((rotated.height(), rotated.width()), (3, 2));
+        assert_eq!(rotated.col(1).copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(rotated.get(MatrixIndex { row: 0, col: 2 }), None);
+    }
+
+    #[test]
+    fn view_mut() {
+        let mut matrix = matrix();
+        let mut view = matrix.view_mut(Orientation::ROTATED_CCW);
+        view[MatrixIndex { row: 0, col: 1 }] = 0;
+        assert_eq!(matrix.data(), [1, 2, 3, 4, 5, 0]);
+    }
+}
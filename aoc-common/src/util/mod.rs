@@ -3,19 +3,39 @@ pub type GenericResult<T> = Result<T, Box<dyn std::error::Error>>;
 mod array_stack;
 pub use array_stack::ArrayStack;
 
+mod assume;
+pub use assume::UnsupportedInput;
+
 mod bisect;
 pub use bisect::{Bisect, bisect};
 
 mod bitset;
 pub use bitset::BitSet;
 
+mod direction;
+pub use direction::{Direction4, Direction8};
+
+mod image;
+
+mod input_error;
+pub use input_error::{CharParseError, InputError, parse_chars};
+
+mod interner;
+pub use interner::Interner;
+
 pub mod iter;
 
 pub mod lexer;
 pub use lexer::Lexer;
 
+mod matrix_view;
+pub use matrix_view::{MatrixView, MatrixViewMut, Orientation};
+
 mod number_range;
 pub use number_range::NumberRange;
 
+mod sparse_grid;
+pub use sparse_grid::{Point, SparseGrid};
+
 mod vecmatrix;
-pub use vecmatrix::{MatrixIndex, VecMatrix};
+pub use vecmatrix::{GridWidthError, MatrixIndex, VecMatrix};
@@ -0,0 +1,311 @@
+use std::{
+    collections::HashMap,
+    ops::{Add, Index, Sub},
+};
+
+use super::{Direction4, Direction8, MatrixIndex, VecMatrix};
+
+// A signed grid point, rows grow down like in `MatrixIndex`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    #[must_use]
+    pub const fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    #[must_use]
+    pub fn step(self, direction: impl Into<Direction8>, n: i64) -> Self {
+        let (row, col) = direction.into().offset();
+        Self {
+            row: self.row + row as i64 * n,
+            col: self.col + col as i64 * n,
+        }
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction4::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction, 1))
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction, 1))
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl From<MatrixIndex> for Point {
+    fn from(idx: MatrixIndex) -> Self {
+        let coord = |value| i64::try_from(value).expect("Matrix index should fit into i64");
+        Self::new(coord(idx.row), coord(idx.col))
+    }
+}
+
+// Cells at arbitrary points, for grids which grow or go negative. The bounding box of the occupied
+// points is kept up to date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Bounds,
+}
+
+impl<T> SparseGrid<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { cells: HashMap::new(), bounds: None }
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.bounds = Some(grow_bounds(self.bounds, point));
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+
+        // Only a point on the edge of the box can shrink it
+        if let Some((min, max)) = self.bounds {
+            let on_edge =
+                [min.row, max.row].contains(&point.row) || [min.col, max.col].contains(&point.col);
+            if on_edge {
+                self.update_bounds();
+            }
+        }
+
+        Some(value)
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(Point, &mut T) -> bool) {
+        self.cells.retain(|&point, value| keep(point, value));
+        self.update_bounds();
+    }
+
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // Top left and bottom right corners, both inclusive. `None` for an empty grid
+    #[must_use]
+    pub const fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    // Number of points in the bounding box, occupied or not
+    #[must_use]
+    pub fn area(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| {
+            let size = max - min + Point::new(1, 1);
+            to_usize(size.row) * to_usize(size.col)
+        })
+    }
+
+    // Arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        self.cells.keys().copied()
+    }
+
+    // Occupied neighbours only
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours4()
+            .filter_map(|next| self.get(next).map(|value| (next, value)))
+    }
+
+    // Occupied neighbours only, including diagonals
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours8()
+            .filter_map(|next| self.get(next).map(|value| (next, value)))
+    }
+
+    // Every cell of the matrix, `offset` is the point of its top left cell
+    #[must_use]
+    pub fn from_matrix(matrix: &VecMatrix<T>, offset: Point) -> Self
+    where
+        T: Clone,
+    {
+        matrix
+            .iter_enumerate()
+            .map(|(idx, value)| (offset + idx.into(), value.clone()))
+            .collect()
+    }
+
+    // The bounding box as a matrix with `empty` in unoccupied cells, together with the point of its
+    // top left cell
+    #[must_use]
+    pub fn to_matrix(&self, empty: T) -> (VecMatrix<T>, Point)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return (VecMatrix::new(0), Point::default());
+        };
+
+        let mut matrix = VecMatrix::new(to_usize(max.col - min.col + 1));
+        for row in min.row..=max.row {
+            for col in min.col..=max.col {
+                let value = self.get(Point::new(row, col)).unwrap_or(&empty);
+                matrix.push(value.clone());
+            }
+        }
+
+        (matrix, min)
+    }
+
+    // The bounding box in text, one line per row like `VecMatrix::render`
+    pub fn render(&self, cell: impl Fn(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+
+        let mut render = String::new();
+        for row in min.row..=max.row {
+            render.extend((min.col..=max.col).map(|col| cell(self.get(Point::new(row, col)))));
+            render.push('\n');
+        }
+        render
+    }
+
+    fn update_bounds(&mut self) {
+        self.bounds = self
+            .cells
+            .keys()
+            .copied()
+            .fold(None, |bounds, point| Some(grow_bounds(bounds, point)));
+    }
+}
+
+type Bounds = Option<(Point, Point)>;
+
+fn grow_bounds(bounds: Bounds, point: Point) -> (Point, Point) {
+    let Some((min, max)) = bounds else {
+        return (point, point);
+    };
+
+    (
+        Point::new(min.row.min(point.row), min.col.min(point.col)),
+        Point::new(max.row.max(point.row), max.col.max(point.col)),
+    )
+}
+
+fn to_usize(value: i64) -> usize {
+    usize::try_from(value).expect("Grid size should fit into usize")
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point).unwrap()
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Point, SparseGrid};
+    use crate::util::{Direction4, VecMatrix};
+
+    #[test]
+    fn sparse_grid() {
+        let mut grid: SparseGrid<char> = [(Point::new(-1, 2), 'a'), (Point::new(1, -1), 'b')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.bounds(), Some((Point::new(-1, -1), Point::new(1, 2))));
+        assert_eq!(grid.area(), 12);
+        assert_eq!(
+            grid.render(|cell| *cell.unwrap_or(&'.')),
+            "...a\n....\nb...\n"
+        );
+
+        let (matrix, offset) = grid.to_matrix('.');
+        assert_eq!(
+            (matrix.width(), matrix.height(), offset),
+            (4, 3, Point::new(-1, -1))
+        );
+        let copy = SparseGrid::from_matrix(&matrix, offset);
+        assert_eq!(copy.len(), 12);
+        assert_eq!(copy[Point::new(-1, 2)], 'a');
+
+        grid.insert(Point::new(0, 0), 'c');
+        assert_eq!(grid.neighbours8(Point::new(0, 1)).count(), 2);
+        assert_eq!(grid.neighbours4(Point::new(0, 1)).count(), 1);
+
+        grid.remove(Point::new(1, -1));
+        assert_eq!(grid.bounds(), Some((Point::new(-1, 0), Point::new(0, 2))));
+
+        let point = Point::new(0, 0).step(Direction4::Left, 3);
+        assert_eq!(point, Point::new(0, -3));
+        assert_eq!(
+            SparseGrid::<u8>::from_matrix(&VecMatrix::new(0), point).bounds(),
+            None
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use super::{Direction4, Direction8, InputError, Point, parse_chars};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MatrixIndex {
@@ -6,6 +12,44 @@ pub struct MatrixIndex {
     pub col: usize,
 }
 
+impl MatrixIndex {
+    // `n` steps towards `direction`, `None` if that goes above the first row or left of the first
+    // column. The other bounds are up to the matrix, see `VecMatrix::step`
+    #[must_use]
+    pub fn step(self, direction: impl Into<Direction8>, n: usize) -> Option<Self> {
+        const fn shift(value: usize, delta: isize, n: usize) -> Option<usize> {
+            match delta {
+                ..0 => value.checked_sub(n),
+                0 => Some(value),
+                _ => value.checked_add(n),
+            }
+        }
+
+        let (row_delta, col_delta) = direction.into().offset();
+        Some(Self {
+            row: shift(self.row, row_delta, n)?,
+            col: shift(self.col, col_delta, n)?,
+        })
+    }
+
+    // Index for a point on the plane tiled with copies of a `width` x `height` area, together with
+    // the copy it is in, e.g. (-1, 0) for the one above. Panics if the area is empty
+    #[must_use]
+    pub fn wrap(point: Point, width: usize, height: usize) -> (Self, Point) {
+        let size = |value| i64::try_from(value).expect("Matrix size should fit into i64");
+        let (width, height) = (size(width), size(height));
+        assert!(width > 0 && height > 0, "Can't wrap into an empty area");
+
+        let coord = |value| usize::try_from(value).expect("Wrapped point should be positive");
+        let idx = Self {
+            row: coord(point.row.rem_euclid(height)),
+            col: coord(point.col.rem_euclid(width)),
+        };
+        let tile = Point::new(point.row.div_euclid(height), point.col.div_euclid(width));
+        (idx, tile)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct VecMatrix<T> {
     data: Vec<T>,
@@ -37,7 +81,7 @@ impl<T> VecMatrix<T> {
 
     #[must_use]
     pub const fn height(&self) -> usize {
-        self.len() / self.width()
+        if self.width() == 0 { 0 } else { self.len() / self.width() }
     }
 
     pub fn push(&mut self, elem: T) {
@@ -74,6 +118,21 @@ impl<T> VecMatrix<T> {
             .and_then(|data_idx| self.data.get_mut(data_idx))
     }
 
+    // `MatrixIndex::wrap` with the size of the matrix, `None` for an empty matrix
+    #[must_use]
+    pub fn wrap(&self, point: Point) -> Option<(MatrixIndex, Point)> {
+        (self.height() > 0).then(|| MatrixIndex::wrap(point, self.width(), self.height()))
+    }
+
+    #[must_use]
+    pub fn get_wrapped(&self, point: Point) -> Option<&T> {
+        self.wrap(point).map(|(idx, _)| &self[idx])
+    }
+
+    pub fn get_wrapped_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.wrap(point).map(|(idx, _)| &mut self[idx])
+    }
+
     #[must_use]
     pub const fn next_left(&self, idx: MatrixIndex) -> Option<MatrixIndex> {
         let MatrixIndex { row, col } = idx;
@@ -83,7 +142,7 @@ impl<T> VecMatrix<T> {
     #[must_use]
     pub const fn next_right(&self, idx: MatrixIndex) -> Option<MatrixIndex> {
         let MatrixIndex { row, col } = idx;
-        if col >= self.width() - 1 {
+        if col + 1 >= self.width() {
             None
         } else {
             Some(MatrixIndex { row, col: col + 1 })
@@ -99,13 +158,41 @@ impl<T> VecMatrix<T> {
     #[must_use]
     pub const fn next_down(&self, idx: MatrixIndex) -> Option<MatrixIndex> {
         let MatrixIndex { row, col } = idx;
-        if row >= self.height() - 1 {
+        if row + 1 >= self.height() {
             None
         } else {
             Some(MatrixIndex { row: row + 1, col })
         }
     }
 
+    #[must_use]
+    pub const fn contains(&self, idx: MatrixIndex) -> bool {
+        idx.row < self.height() && idx.col < self.width()
+    }
+
+    #[must_use]
+    pub fn step(
+        &self,
+        idx: MatrixIndex,
+        direction: impl Into<Direction8>,
+        n: usize,
+    ) -> Option<MatrixIndex> {
+        idx.step(direction, n).filter(|&next| self.contains(next))
+    }
+
+    pub fn neighbours4(&self, idx: MatrixIndex) -> impl Iterator<Item = MatrixIndex> {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(idx, direction, 1))
+    }
+
+    // Includes diagonals
+    pub fn neighbours8(&self, idx: MatrixIndex) -> impl Iterator<Item = MatrixIndex> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(idx, direction, 1))
+    }
+
     pub fn finish_row_with(&mut self, func: impl Fn() -> T) {
         let last_row_len = self.len() % self.width();
         self.extend((last_row_len..self.width()).map(|_| func()));
@@ -125,6 +212,28 @@ impl<T> VecMatrix<T> {
             .map(move |(idx, item)| (get_matrix_idx(idx, width), item))
     }
 
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        // A matrix without data still has `width` columns, they are just empty
+        (0..self.width).map(|col| {
+            let data: &[T] = self.data.get(col..).unwrap_or_default();
+            data.iter().step_by(self.width)
+        })
+    }
+
+    // One line per row, each ending with a newline
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let mut render = String::with_capacity(self.len() + self.height());
+        for row in self.rows() {
+            render.extend(row.iter().map(&cell));
+            render.push('\n');
+        }
+        render
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.data.iter()
     }
@@ -138,6 +247,65 @@ impl<T> VecMatrix<T> {
     }
 }
 
+#[derive(Debug)]
+pub struct GridWidthError {
+    expected: usize,
+    found: usize,
+}
+
+impl Display for GridWidthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected a row of {} chars, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl Error for GridWidthError {}
+
+impl<T> VecMatrix<T>
+where
+    T: TryFrom<char>,
+    T::Error: Error + 'static,
+{
+    // One row per line and one cell per char, the width is taken from the first line. Errors only
+    // carry the column, `Solution` knows which line was being read
+    pub fn parse_lines(lines: impl IntoIterator<Item = String>) -> Result<Self, InputError> {
+        let mut matrix = Self::new(0);
+        for line in lines {
+            matrix.parse_row(line)?;
+        }
+        Ok(matrix)
+    }
+
+    // Reads rows up to the first blank line, which is consumed, for inputs with more after the grid
+    pub fn parse_grid(lines: &mut impl Iterator<Item = String>) -> Result<Self, InputError> {
+        Self::parse_lines(lines.take_while(|line| !line.is_empty()))
+    }
+
+    fn parse_row(&mut self, line: String) -> Result<(), InputError> {
+        let width = line.chars().count();
+        if self.is_empty() {
+            self.width = width;
+        } else if width != self.width {
+            let error = GridWidthError { expected: self.width, found: width };
+            // Point at the first extra char, or at the end of a short row
+            let column = line
+                .char_indices()
+                .nth(self.width)
+                .map_or(line.len(), |(column, _)| column);
+            return Err(InputError::new(error).at_column(column));
+        }
+
+        for cell in parse_chars(line) {
+            self.push(cell?);
+        }
+        Ok(())
+    }
+}
+
 impl<T> Index<MatrixIndex> for VecMatrix<T> {
     type Output = T;
 
@@ -184,3 +352,91 @@ impl<'a, T> IntoIterator for &'a mut VecMatrix<T> {
         self.data.iter_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{MatrixIndex, VecMatrix};
+    use crate::util::{Direction4, Direction8, Point};
+
+    #[test]
+    fn parse_grid() {
+        let mut lines = ["#.", ".#", "", "rest"].into_iter().map(String::from);
+        let grid = VecMatrix::<char>::parse_grid(&mut lines).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[MatrixIndex { row: 1, col: 1 }], '#');
+        assert_eq!(lines.next().as_deref(), Some("rest"));
+
+        assert_eq!(grid.render(|&cell| cell), "#.\n.#\n");
+
+        let lines = ["#.#", "#."].into_iter().map(String::from);
+        let error = VecMatrix::<char>::parse_lines(lines).unwrap_err();
+        assert_eq!(error.column(), Some(2));
+        assert_eq!(error.to_string(), "expected a row of 3 chars, found 2");
+
+        let lines = ["·#", "#·x"].into_iter().map(String::from);
+        let error = VecMatrix::<char>::parse_lines(lines).unwrap_err();
+        assert_eq!(error.to_string(), "expected a row of 2 chars, found 3");
+        let error = error.at_line(2).with_text("#·x");
+        assert!(error.to_string().starts_with("line 2, column 3:"));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = VecMatrix::with_data((0..6).collect(), 3);
+        let corner = MatrixIndex { row: 1, col: 2 };
+        assert_eq!(
+            grid.neighbours4(corner)
+                .map(|idx| grid[idx])
+                .collect::<Vec<_>>(),
+            [2, 4]
+        );
+        assert_eq!(
+            grid.neighbours8(corner)
+                .map(|idx| grid[idx])
+                .collect::<Vec<_>>(),
+            [2, 4, 1]
+        );
+
+        assert_eq!(
+            grid.step(corner, Direction4::Left, 2),
+            Some(MatrixIndex { row: 1, col: 0 })
+        );
+        assert_eq!(grid.step(corner, Direction4::Left, 3), None);
+        assert_eq!(grid.step(corner, Direction8::DownLeft, 1), None);
+
+        let empty = VecMatrix::<u8>::new(0);
+        let origin = MatrixIndex { row: 0, col: 0 };
+        assert_eq!(empty.height(), 0);
+        assert_eq!(
+            (empty.next_right(origin), empty.next_down(origin)),
+            (None, None)
+        );
+        assert_eq!(empty.neighbours8(origin).count(), 0);
+    }
+
+    #[test]
+    fn wrap() {
+        let grid = VecMatrix::with_data((0..6).collect::<Vec<u8>>(), 3);
+        assert_eq!(
+            grid.wrap(Point::new(-1, 7)),
+            Some((MatrixIndex { row: 1, col: 1 }, Point::new(-1, 2)))
+        );
+        assert_eq!(grid.get_wrapped(Point::new(4, -3)), Some(&0));
+        assert_eq!(VecMatrix::<u8>::new(3).wrap(Point::new(0, 0)), None);
+        assert_eq!(
+            MatrixIndex::wrap(Point::new(-4, 2), 1, 3),
+            (MatrixIndex { row: 2, col: 0 }, Point::new(-2, 2))
+        );
+    }
+
+    #[test]
+    fn empty_rows_and_cols() {
+        let empty = VecMatrix::<u8>::new(3);
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(
+            empty.cols().map(Iterator::count).collect::<Vec<_>>(),
+            [0, 0, 0]
+        );
+        assert_eq!(VecMatrix::<u8>::new(0).cols().count(), 0);
+    }
+}
@@ -0,0 +1,306 @@
+use std::path::PathBuf;
+
+use aoc_common::Format;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc list [<year>]
+    aoc new <year> <day> [<title>]
+    aoc run <year> (<day> | <from>-<to> | --all) [--input (<path> | -)] [--verify]
+        [--part <part>] [--time | --bench <iterations>] [--format (human | json)]";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingArgument(&'static str),
+    UnexpectedArgument(String),
+    InvalidNumber(String),
+    InputWithMultipleDays,
+    InvalidPart(u32),
+    InvalidIterations,
+    InvalidFormat(String),
+}
+
+impl std::fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingCommand => write!(f, "No command given"),
+            Self::UnknownCommand(command) => write!(f, "Unknown command `{command}`"),
+            Self::MissingArgument(name) => write!(f, "Missing argument <{name}>"),
+            Self::UnexpectedArgument(arg) => write!(f, "Unexpected argument `{arg}`"),
+            Self::InvalidNumber(arg) => write!(f, "`{arg}` is not a valid number"),
+            Self::InputWithMultipleDays => write!(f, "--input can only be used with a single day"),
+            Self::InvalidPart(part) => write!(f, "There is no part {part}, only 1 and 2"),
+            Self::InvalidIterations => write!(f, "--bench needs at least 1 iteration"),
+            Self::InvalidFormat(format) => {
+                write!(f, "Unknown format `{format}`, expected `human` or `json`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Days {
+    All,
+    Range(u32, u32),
+}
+
+impl Days {
+    pub const fn contains(self, day: u32) -> bool {
+        match self {
+            Self::All => true,
+            Self::Range(from, to) => from <= day && day <= to,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub year: u32,
+    pub days: Days,
+    pub input: Option<PathBuf>,
+    pub verify: bool,
+    pub iterations: Option<usize>,
+    pub part: Option<usize>,
+    pub format: Format,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    List {
+        year: Option<u32>,
+    },
+    New {
+        year: u32,
+        day: u32,
+        title: Option<String>,
+    },
+    Run(RunArgs),
+}
+
+fn parse_number(s: &str) -> Result<u32, ArgsError> {
+    s.parse()
+        .map_err(|_| ArgsError::InvalidNumber(s.to_owned()))
+}
+
+fn parse_days(s: &str) -> Result<Days, ArgsError> {
+    if s == "--all" {
+        return Ok(Days::All);
+    }
+
+    if let Some((from, to)) = s.split_once('-') {
+        return Ok(Days::Range(parse_number(from)?, parse_number(to)?));
+    }
+
+    let day = parse_number(s)?;
+    Ok(Days::Range(day, day))
+}
+
+fn parse_list(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let year = args.next().map(|s| parse_number(&s)).transpose()?;
+
+    if let Some(arg) = args.next() {
+        return Err(ArgsError::UnexpectedArgument(arg));
+    }
+
+    Ok(Command::List { year })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let year = parse_number(&args.next().ok_or(ArgsError::MissingArgument("year"))?)?;
+    let day = parse_number(&args.next().ok_or(ArgsError::MissingArgument("day"))?)?;
+    let title = args.next();
+
+    if let Some(arg) = args.next() {
+        return Err(ArgsError::UnexpectedArgument(arg));
+    }
+
+    Ok(Command::New { year, day, title })
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let year = parse_number(&args.next().ok_or(ArgsError::MissingArgument("year"))?)?;
+    let days = parse_days(&args.next().ok_or(ArgsError::MissingArgument("day"))?)?;
+
+    let mut run = RunArgs {
+        year,
+        days,
+        input: None,
+        verify: false,
+        iterations: None,
+        part: None,
+        format: Format::Human,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or(ArgsError::MissingArgument("input"))?;
+                run.input = Some(PathBuf::from(path));
+            }
+            "--verify" => run.verify = true,
+            "--part" => {
+                let part = parse_number(&args.next().ok_or(ArgsError::MissingArgument("part"))?)?;
+                if !(1..=2).contains(&part) {
+                    return Err(ArgsError::InvalidPart(part));
+                }
+                run.part = Some(part as usize);
+            }
+            "--time" => run.iterations = Some(1),
+            "--bench" => {
+                let iterations = args
+                    .next()
+                    .ok_or(ArgsError::MissingArgument("iterations"))?;
+                let iterations = parse_number(&iterations)?;
+                if iterations == 0 {
+                    return Err(ArgsError::InvalidIterations);
+                }
+                run.iterations = Some(iterations as usize);
+            }
+            "--format" => {
+                let format = args.next().ok_or(ArgsError::MissingArgument("format"))?;
+                run.format = match format.as_str() {
+                    "human" => Format::Human,
+                    "json" => Format::Json,
+                    _ => return Err(ArgsError::InvalidFormat(format)),
+                };
+            }
+            _ => return Err(ArgsError::UnexpectedArgument(arg)),
+        }
+    }
+
+    if run.input.is_some() && !matches!(days, Days::Range(from, to) if from == to) {
+        return Err(ArgsError::InputWithMultipleDays);
+    }
+
+    Ok(Command::Run(run))
+}
+
+pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let command = args.next().ok_or(ArgsError::MissingCommand)?;
+
+    match command.as_str() {
+        "list" => parse_list(args),
+        "new" => parse_new(args),
+        "run" => parse_run(args),
+        _ => Err(ArgsError::UnknownCommand(command)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use aoc_common::Format;
+
+    use super::{ArgsError, Command, Days, RunArgs, parse};
+
+    fn parse_str(args: &str) -> Result<Command, ArgsError> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    fn parse_run(args: &str) -> Result<RunArgs, ArgsError> {
+        match parse_str(args)? {
+            Command::Run(run) => Ok(run),
+            command => panic!("Expected a run command, got {command:?}"),
+        }
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(parse_str("list"), Ok(Command::List { year: None }));
+        assert_eq!(
+            parse_str("new 2024 3 Mull"),
+            Ok(Command::New {
+                year: 2024,
+                day: 3,
+                title: Some("Mull".to_owned())
+            })
+        );
+        assert_eq!(parse_str(""), Err(ArgsError::MissingCommand));
+        assert_eq!(
+            parse_str("solve 2024"),
+            Err(ArgsError::UnknownCommand("solve".to_owned()))
+        );
+        assert_eq!(
+            parse_str("list 2024 2025"),
+            Err(ArgsError::UnexpectedArgument("2025".to_owned()))
+        );
+    }
+
+    #[test]
+    fn run_flags() {
+        assert_eq!(
+            parse_run("run 2024 1-5 --verify --part 2 --bench 10 --format json"),
+            Ok(RunArgs {
+                year: 2024,
+                days: Days::Range(1, 5),
+                input: None,
+                verify: true,
+                iterations: Some(10),
+                part: Some(2),
+                format: Format::Json,
+            })
+        );
+
+        let run = parse_run("run 2023 --all --time").unwrap();
+        assert_eq!((run.days, run.iterations), (Days::All, Some(1)));
+
+        let run = parse_run("run 2023 7 --input - --format human").unwrap();
+        assert_eq!(run.input, Some(PathBuf::from("-")));
+        assert_eq!(run.format, Format::Human);
+    }
+
+    #[test]
+    fn run_errors() {
+        assert_eq!(
+            parse_run("run 2024"),
+            Err(ArgsError::MissingArgument("day"))
+        );
+        assert_eq!(
+            parse_run("run 2024 1 --bench"),
+            Err(ArgsError::MissingArgument("iterations"))
+        );
+        assert_eq!(
+            parse_run("run 2024 1 --part"),
+            Err(ArgsError::MissingArgument("part"))
+        );
+        assert_eq!(
+            parse_run("run 2024 1 --format"),
+            Err(ArgsError::MissingArgument("format"))
+        );
+
+        assert_eq!(
+            parse_run("run 2024 1 --bench 0"),
+            Err(ArgsError::InvalidIterations)
+        );
+        assert_eq!(
+            parse_run("run 2024 1 --bench many"),
+            Err(ArgsError::InvalidNumber("many".to_owned()))
+        );
+        assert_eq!(
+            parse_run("run 2024 1 --part 3"),
+            Err(ArgsError::InvalidPart(3))
+        );
+        assert_eq!(
+            parse_run("run 2024 1 --format yaml"),
+            Err(ArgsError::InvalidFormat("yaml".to_owned()))
+        );
+        assert_eq!(
+            parse_run("run 2024 1 --fast"),
+            Err(ArgsError::UnexpectedArgument("--fast".to_owned()))
+        );
+
+        // A single input file can't feed several days
+        assert_eq!(
+            parse_run("run 2024 1-2 --input day1.txt"),
+            Err(ArgsError::InputWithMultipleDays)
+        );
+        assert_eq!(
+            parse_run("run 2024 --all --input day1.txt"),
+            Err(ArgsError::InputWithMultipleDays)
+        );
+    }
+}
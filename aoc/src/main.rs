@@ -0,0 +1,164 @@
+#![warn(clippy::all, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::missing_inline_in_public_items,
+    clippy::single_call_fn,
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::fallible_impl_from,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+
+use std::path::{Path, PathBuf};
+
+mod cli;
+mod scaffold;
+use cli::{Command, Days, RunArgs};
+
+use aoc_common::{Outcome, RunOptions, Year, util};
+
+const YEARS: &[Year] = &[
+    aoc_2022::YEAR,
+    aoc_2023::YEAR,
+    aoc_2024::YEAR,
+    aoc_2025::YEAR,
+];
+
+#[derive(Debug)]
+enum RunError {
+    UnknownYear(u32),
+    UnknownDay { year: u32, day: u32 },
+    NoDaysSelected { year: u32, days: Days },
+}
+
+impl std::fmt::Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownYear(year) => write!(f, "No solutions registered for year {year}"),
+            Self::UnknownDay { year, day } => {
+                write!(f, "No solution registered for {year} day {day}")
+            }
+            Self::NoDaysSelected { year, days } => {
+                write!(f, "No solutions registered for {year} in {days:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+fn find_year(year: u32) -> Result<&'static Year, RunError> {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .ok_or(RunError::UnknownYear(year))
+}
+
+fn list(year: Option<u32>) -> Result<(), RunError> {
+    let years = match year {
+        Some(year) => std::slice::from_ref(find_year(year)?),
+        None => YEARS,
+    };
+
+    for year in years {
+        println!("{}", year.year);
+        for puzzle in year.puzzles {
+            println!("    {:>2}  {}", puzzle.day, puzzle.title);
+        }
+    }
+
+    Ok(())
+}
+
+fn run(args: RunArgs) -> util::GenericResult<bool> {
+    let year = find_year(args.year)?;
+    let answers = if args.verify { Some(year.answers()?) } else { None };
+    let options = |day| RunOptions {
+        expected: answers.as_ref().map(|a| a.get(day)),
+        iterations: args.iterations,
+        part: args.part,
+        format: args.format,
+    };
+
+    if let (Days::Range(day, _), Some(input)) = (args.days, args.input) {
+        let puzzle = year
+            .puzzle(day)
+            .ok_or(RunError::UnknownDay { year: year.year, day })?;
+        return Ok(puzzle.run(input, options(day)) == Outcome::Solved);
+    }
+
+    let puzzles = year
+        .puzzles
+        .iter()
+        .filter(|p| args.days.contains(p.day))
+        .collect::<Vec<_>>();
+
+    if puzzles.is_empty() {
+        return Err(match args.days {
+            Days::Range(from, to) if from == to => {
+                RunError::UnknownDay { year: year.year, day: from }
+            }
+            days => RunError::NoDaysSelected { year: year.year, days },
+        }
+        .into());
+    }
+
+    let mut mismatched = vec![];
+    let mut failed = vec![];
+    for puzzle in puzzles {
+        match puzzle.run(year.input_path(puzzle.day), options(puzzle.day)) {
+            Outcome::Solved => {}
+            Outcome::Mismatch => mismatched.push(puzzle.day.to_string()),
+            Outcome::Error => failed.push(puzzle.day.to_string()),
+        }
+    }
+
+    if !mismatched.is_empty() {
+        eprintln!(
+            "Mismatched answers in {} days: {}",
+            year.year,
+            mismatched.join(", ")
+        );
+    }
+
+    if !failed.is_empty() {
+        eprintln!("Failed {} days: {}", year.year, failed.join(", "));
+    }
+
+    Ok(mismatched.is_empty() && failed.is_empty())
+}
+
+fn workspace_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_owned()
+}
+
+fn main() {
+    let command = match cli::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!();
+            eprintln!("{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::List { year } => list(year).map(|()| true).map_err(Into::into),
+        Command::New { year, day, title } => {
+            scaffold::new_day(&workspace_root(), year, day, title.as_deref()).map(|()| true)
+        }
+        Command::Run(args) => run(args),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+}
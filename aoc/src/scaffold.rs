@@ -0,0 +1,400 @@
+use std::{
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::util;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u32),
+    DayExists {
+        year: u32,
+        day: u32,
+    },
+    MalformedFile {
+        path: PathBuf,
+        expected: &'static str,
+    },
+}
+
+impl std::fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDay(day) => write!(f, "There is no day {day}, only 1 to 25"),
+            Self::DayExists { year, day } => write!(f, "Day {day} of {year} already exists"),
+            Self::MalformedFile { path, expected } => {
+                write!(f, "Cannot find {expected} in {}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+const LINTS: &str = "\
+#![warn(clippy::all, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::missing_inline_in_public_items,
+    clippy::single_call_fn,
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::fallible_impl_from,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+";
+
+struct NewDay<'a> {
+    root: &'a Path,
+    year: u32,
+    day: u32,
+    title: String,
+    name: String,
+}
+
+impl NewDay<'_> {
+    fn crate_dir(&self) -> PathBuf {
+        self.root.join(format!("aoc-{}", self.year))
+    }
+
+    fn module_line(&self) -> String {
+        format!("pub mod day{};", self.day)
+    }
+
+    fn puzzle_line(&self) -> String {
+        format!(
+            "    {day} => day(day{day}::{name}), {title:?};",
+            day = self.day,
+            name = self.name,
+            title = self.title,
+        )
+    }
+
+    // A `Day` implementation, the form `register_year!` asks new days to use
+    fn module(&self) -> String {
+        format!(
+            "\
+use std::fmt::Display;
+
+use aoc_common::{{Day, util}};
+
+pub struct {name};
+
+impl Day for {name} {{
+    type Input = Vec<String>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> util::GenericResult<Self::Input> {{
+        Ok(lines.collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> util::GenericResult<impl Display> {{
+        Ok(0)
+    }}
+
+    fn part2(_input: &Self::Input) -> util::GenericResult<impl Display> {{
+        Ok(0)
+    }}
+}}
+",
+            name = self.name
+        )
+    }
+
+    fn lib(&self) -> String {
+        format!(
+            "{LINTS}\n{module}\n\naoc_common::register_year! {{\n    year: {year},\n{puzzle}\n}}\n",
+            module = self.module_line(),
+            year = self.year,
+            puzzle = self.puzzle_line(),
+        )
+    }
+}
+
+// "Print Queue" becomes `PrintQueue`, titles without a usable name fall back to `DayN`
+fn struct_name(day: u32, title: Option<&str>) -> String {
+    let name = title
+        .unwrap_or_default()
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter_map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next()?;
+            Some(first.to_ascii_uppercase().to_string() + chars.as_str())
+        })
+        .collect::<String>();
+
+    if name.starts_with(|ch: char| ch.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("Day{day}")
+    }
+}
+
+// Lines strictly between the first line matching `start` and the following line matching `end`
+fn find_block(
+    lines: &[String],
+    start: impl Fn(&str) -> bool,
+    end: impl Fn(&str) -> bool,
+) -> Option<Range<usize>> {
+    let first = lines.iter().position(|line| start(line))? + 1;
+    let last = first + lines[first..].iter().position(|line| end(line))?;
+    Some(first..last)
+}
+
+// Keeps the block ordered by `key`, lines without a key are skipped. Nothing is inserted when
+// the block already has a line with the same key
+fn insert_ordered<K: Ord>(
+    lines: &mut Vec<String>,
+    block: Range<usize>,
+    line: String,
+    key: impl Fn(&str) -> Option<K>,
+) -> bool {
+    let Some(new_key) = key(&line) else {
+        return false;
+    };
+
+    let mut pos = block.end;
+    let mut last_entry = None;
+    for idx in block {
+        let Some(existing) = key(&lines[idx]) else {
+            continue;
+        };
+
+        if existing == new_key {
+            return false;
+        }
+        if existing > new_key {
+            pos = idx;
+            break;
+        }
+        last_entry = Some(idx);
+    }
+
+    if pos == lines.len() || key(&lines[pos]).is_none() {
+        pos = last_entry.map_or(pos, |idx| idx + 1);
+    }
+    lines.insert(pos, line);
+    true
+}
+
+fn edit_file(
+    path: &Path,
+    edit: impl FnOnce(&mut Vec<String>) -> Option<bool>,
+    expected: &'static str,
+) -> util::GenericResult<()> {
+    let mut lines = fs::read_to_string(path)?
+        .lines()
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+
+    let changed = edit(&mut lines)
+        .ok_or_else(|| ScaffoldError::MalformedFile { path: path.to_owned(), expected })?;
+
+    if changed {
+        fs::write(path, lines.join("\n") + "\n")?;
+        println!("Updated {}", path.display());
+    }
+    Ok(())
+}
+
+fn year_key(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    line.trim()
+        .strip_prefix(prefix)?
+        .split_once(suffix)?
+        .0
+        .parse()
+        .ok()
+}
+
+fn create_year(new: &NewDay<'_>) -> util::GenericResult<()> {
+    let year = new.year;
+    let crate_dir = new.crate_dir();
+
+    fs::create_dir_all(crate_dir.join("src"))?;
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"aoc-{year}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n\
+             [dependencies]\naoc-common = {{ path = \"../aoc-common\" }}\n"
+        ),
+    )?;
+    fs::write(crate_dir.join("src/lib.rs"), new.lib())?;
+    println!("Created {}", crate_dir.display());
+
+    edit_file(
+        &new.root.join("Cargo.toml"),
+        |lines| {
+            let block = find_block(lines, |l| l.starts_with("members"), |l| l.starts_with(']'))?;
+            let member = format!("    \"aoc-{year}\",");
+            Some(insert_ordered(lines, block, member, |l| {
+                year_key(l, "\"aoc-", "\"")
+            }))
+        },
+        "workspace members",
+    )?;
+
+    edit_file(
+        &new.root.join("aoc/Cargo.toml"),
+        |lines| {
+            let block = find_block(lines, |l| l == "[dependencies]", |l| l.starts_with('['))
+                .or_else(|| {
+                    let start = lines.iter().position(|l| l == "[dependencies]")? + 1;
+                    Some(start..lines.len())
+                })?;
+            let dependency = format!("aoc-{year} = {{ path = \"../aoc-{year}\" }}");
+            Some(insert_ordered(lines, block, dependency, |l| {
+                year_key(l, "aoc-", " ")
+            }))
+        },
+        "dependencies",
+    )?;
+
+    edit_file(
+        &new.root.join("aoc/src/main.rs"),
+        |lines| {
+            let block = find_block(lines, |l| l.starts_with("const YEARS"), |l| l == "];")?;
+            let entry = format!("    aoc_{year}::YEAR,");
+            Some(insert_ordered(lines, block, entry, |l| {
+                year_key(l, "aoc_", "::")
+            }))
+        },
+        "the YEARS list",
+    )
+}
+
+pub fn new_day(root: &Path, year: u32, day: u32, title: Option<&str>) -> util::GenericResult<()> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day).into());
+    }
+
+    let new = NewDay {
+        root,
+        year,
+        day,
+        title: title.map_or_else(
+            || format!("Day {day}"),
+            |title| format!("Day {day}: {title}"),
+        ),
+        name: struct_name(day, title),
+    };
+
+    let crate_dir = new.crate_dir();
+    let module = crate_dir.join(format!("src/day{day}/mod.rs"));
+    if module.exists() {
+        return Err(ScaffoldError::DayExists { year, day }.into());
+    }
+
+    if !crate_dir.exists() {
+        create_year(&new)?;
+    }
+
+    fs::create_dir_all(module.parent().unwrap_or(&crate_dir))?;
+    fs::write(&module, new.module())?;
+    println!("Created {}", module.display());
+
+    edit_file(
+        &crate_dir.join("src/lib.rs"),
+        |lines| {
+            let register = lines
+                .iter()
+                .position(|l| l.starts_with("aoc_common::register_year!"))?;
+            let modules = insert_ordered(lines, 0..register, new.module_line(), |l| {
+                l.strip_prefix("pub mod ")?
+                    .strip_suffix(';')
+                    .map(ToOwned::to_owned)
+            });
+
+            let block = find_block(
+                lines,
+                |l| l.starts_with("aoc_common::register_year!"),
+                |l| l == "}",
+            )?;
+            let puzzles = insert_ordered(lines, block, new.puzzle_line(), |l| {
+                l.trim().split_once(" =>")?.0.parse::<u32>().ok()
+            });
+
+            Some(modules || puzzles)
+        },
+        "the register_year! block",
+    )?;
+
+    // Inputs cannot be downloaded without logging in, keep an existing one
+    let input = crate_dir.join(format!("inputs/day{day}.txt"));
+    if !input.exists() {
+        fs::create_dir_all(crate_dir.join("inputs"))?;
+        fs::write(&input, "")?;
+        println!("Created {}", input.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{ScaffoldError, new_day, struct_name};
+
+    #[test]
+    fn names() {
+        assert_eq!(struct_name(5, Some("Print Queue")), "PrintQueue");
+        assert_eq!(struct_name(3, Some("Mull It Over?")), "MullItOver");
+        assert_eq!(struct_name(7, Some("2048")), "Day7");
+        assert_eq!(struct_name(9, None), "Day9");
+    }
+
+    #[test]
+    fn new_days() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc-2022\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[package]\nname = \"aoc\"\n\n[dependencies]\naoc-2022 = { path = \"../aoc-2022\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/main.rs"),
+            "const YEARS: &[Year] = &[\n    aoc_2022::YEAR,\n];\n",
+        )
+        .unwrap();
+
+        new_day(&root, 2099, 3, Some("Mull It Over")).unwrap();
+        new_day(&root, 2099, 1, None).unwrap();
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        let module = read("aoc-2099/src/day3/mod.rs");
+        assert!(module.contains("pub struct MullItOver;\n\nimpl Day for MullItOver {"));
+        assert!(read("aoc-2099/src/lib.rs").ends_with(
+            "pub mod day1;\npub mod day3;\n\n\
+             aoc_common::register_year! {\n    \
+             year: 2099,\n    \
+             1 => day(day1::Day1), \"Day 1\";\n    \
+             3 => day(day3::MullItOver), \"Day 3: Mull It Over\";\n\
+             }\n"
+        ));
+        assert!(read("Cargo.toml").contains("    \"aoc-2022\",\n    \"aoc-2099\",\n]"));
+        assert!(read("aoc/Cargo.toml").ends_with("aoc-2099 = { path = \"../aoc-2099\" }\n"));
+        assert!(read("aoc/src/main.rs").contains("    aoc_2099::YEAR,\n];"));
+        assert_eq!(read("aoc-2099/inputs/day3.txt"), "");
+
+        let error = new_day(&root, 2099, 3, None).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref(),
+            Some(ScaffoldError::DayExists { year: 2099, day: 3 })
+        ));
+        let error = new_day(&root, 2099, 26, None).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref(),
+            Some(ScaffoldError::InvalidDay(26))
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}